@@ -0,0 +1,42 @@
+use std::{
+    io::{self, Write},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use minsk_language::code_analysis::host::Host;
+
+/// Runs programs against the process's stdin and stdout.
+pub(crate) struct ConsoleHost {
+    seed: u64,
+}
+
+impl ConsoleHost {
+    pub(crate) fn new() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or_default();
+        // xorshift gets stuck on zero
+        Self { seed: nanos | 1 }
+    }
+}
+
+impl Host for ConsoleHost {
+    fn print(&mut self, text: &str) {
+        println!("{}", text);
+    }
+
+    fn input(&mut self) -> String {
+        io::stdout().flush().ok();
+        let mut line = String::new();
+        io::stdin().read_line(&mut line).ok();
+        line.trim_end_matches(&['\r', '\n'][..]).to_string()
+    }
+
+    fn random(&mut self, max: i32) -> i32 {
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+        (self.seed % max as u64) as i32
+    }
+}
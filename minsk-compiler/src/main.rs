@@ -1,23 +1,30 @@
+mod console_host;
+
+use console_host::ConsoleHost;
 use crossterm::{
     style::{Color, ResetColor, SetForegroundColor},
     terminal::{Clear, ClearType},
     ExecutableCommand,
 };
 use minsk_language::code_analysis::{
-    compilation::Compilation, minsk_value::MinskValue, syntax::syntax_tree::SyntaxTree,
+    compilation::Compilation, compilation_options::CompilationOptions,
+    evaluation_result::EvaluationError, minsk_value::MinskValue, syntax::syntax_tree::SyntaxTree,
     text::text_span::TextSpan, variable_symbol::VariableSymbol,
 };
 use std::{
     collections::HashMap,
-    io::{self, BufRead, BufReader, Write},
+    fmt::Display,
+    io::{self, Stdout, Write},
 };
 
 fn main() -> anyhow::Result<()> {
     let mut stdout = io::stdout();
-    let mut reader = BufReader::new(io::stdin());
+    let stdin = io::stdin();
+    let mut host = ConsoleHost::new();
     let mut line = String::new();
     let mut text_builder = String::new();
     let mut show_tree = false;
+    let mut options = CompilationOptions::default();
     let mut variables = HashMap::<VariableSymbol, MinskValue>::new();
     let mut previous: Option<Compilation> = None;
 
@@ -32,7 +39,7 @@ fn main() -> anyhow::Result<()> {
         }
         stdout.execute(ResetColor)?;
         stdout.flush()?;
-        if reader.read_line(&mut line)? == 0 {
+        if stdin.read_line(&mut line)? == 0 {
             break;
         }
 
@@ -54,7 +61,20 @@ fn main() -> anyhow::Result<()> {
                     );
                     continue;
                 }
+                "#checked" => {
+                    options.checked_arithmetic = !options.checked_arithmetic;
+                    println!(
+                        "{}",
+                        if options.checked_arithmetic {
+                            "Reporting integer overflow"
+                        } else {
+                            "Wrapping integer overflow"
+                        }
+                    );
+                    continue;
+                }
                 "#reset" => previous = None,
+                "" => continue,
                 _ => {}
             }
         }
@@ -63,54 +83,32 @@ fn main() -> anyhow::Result<()> {
         let text = text_builder.clone();
         let tree = SyntaxTree::parse(text.clone());
 
-        if !line.trim().is_empty() && tree.diagnostics().count() > 0 {
+        if !line.trim().is_empty() && !tree.is_complete() {
             continue;
         }
         if show_tree {
             println!("{}", tree.root());
         }
         let mut compilation = if let Some(previous) = previous.clone() {
-            previous.continue_with(tree.clone())
+            previous.continue_with_options(tree.clone(), options)
         } else {
-            Compilation::new(tree.clone())
+            Compilation::with_options(tree.clone(), options)
         };
-        let evaluation_result = compilation.evaluate(&mut variables);
+        let evaluation_result = compilation.evaluate(&mut variables, &mut host);
         match evaluation_result {
-            Err(diagnostics) => {
-                let text = tree.text();
+            Err(EvaluationError::Diagnostics(diagnostics)) => {
                 for diagnostic in diagnostics {
-                    let line_index = text.get_line_index(diagnostic.span.start).unwrap();
-                    let line_number = line_index + 1;
-                    let line = tree.text().lines()[line_index];
-                    let character = diagnostic.span.start - text.lines()[line_index].start() + 1;
-                    println!();
-                    stdout.execute(SetForegroundColor(Color::DarkRed))?;
-                    print!("({}, {}): ", line_number, character);
-                    println!("{}", diagnostic);
-                    stdout.execute(ResetColor)?;
-                    let prefix = &tree.text()[TextSpan {
-                        start: line.start(),
-                        end: diagnostic.span.start,
-                    }]
-                    .iter()
-                    .collect::<String>();
-                    let error = &tree.text()[diagnostic.span].iter().collect::<String>();
-                    let suffix = &tree.text()[TextSpan {
-                        start: diagnostic.span.end,
-                        end: line.end(),
-                    }]
-                    .iter()
-                    .collect::<String>();
-
-                    print!("    {}", prefix);
-                    stdout.execute(SetForegroundColor(Color::DarkRed))?;
-                    print!("{}", error);
-                    stdout.execute(ResetColor)?;
-                    println!("{}", suffix);
+                    print_error(&mut stdout, &tree, diagnostic.span, &diagnostic)?;
                 }
             }
+            Err(EvaluationError::Runtime(error)) => {
+                print_error(&mut stdout, &tree, error.span, &error)?;
+                // whatever ran before the error has already changed the variables
+                previous = Some(compilation);
+            }
             Ok(value) => {
-                if let Some(v) = value {
+                // calls like print() have nothing worth echoing
+                if let Some(v) = value.filter(|v| *v != MinskValue::Null) {
                     stdout.execute(SetForegroundColor(Color::Magenta))?;
                     println!("{}", v);
                     stdout.execute(ResetColor)?;
@@ -123,3 +121,41 @@ fn main() -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+fn print_error(
+    stdout: &mut Stdout,
+    tree: &SyntaxTree,
+    span: TextSpan,
+    message: &impl Display,
+) -> anyhow::Result<()> {
+    let text = tree.text();
+    let line_index = text.get_line_index(span.start).unwrap();
+    let line_number = line_index + 1;
+    let line = text.lines()[line_index];
+    let character = span.start - line.start() + 1;
+    println!();
+    stdout.execute(SetForegroundColor(Color::DarkRed))?;
+    print!("({}, {}): ", line_number, character);
+    println!("{}", message);
+    stdout.execute(ResetColor)?;
+    let prefix = &text[TextSpan {
+        start: line.start(),
+        end: span.start,
+    }]
+    .iter()
+    .collect::<String>();
+    let error = &text[span].iter().collect::<String>();
+    let suffix = &text[TextSpan {
+        start: span.end,
+        end: line.end(),
+    }]
+    .iter()
+    .collect::<String>();
+
+    print!("    {}", prefix);
+    stdout.execute(SetForegroundColor(Color::DarkRed))?;
+    print!("{}", error);
+    stdout.execute(ResetColor)?;
+    println!("{}", suffix);
+    Ok(())
+}
@@ -1,11 +1,19 @@
 pub mod binding;
+mod built_in_functions;
 pub mod compilation;
+pub mod compilation_options;
 pub mod diagnostic;
 mod diagnostic_bag;
 pub mod evaluation_result;
 pub mod evaluator;
+pub mod function_symbol;
+pub mod host;
+mod lowering;
 mod minsk_type;
 pub mod minsk_value;
+pub mod parameter_symbol;
+pub mod runtime_error;
+pub mod struct_symbol;
 pub mod syntax;
 pub mod text;
 pub mod variable_symbol;
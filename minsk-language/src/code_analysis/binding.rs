@@ -1,20 +1,38 @@
 pub(super) mod binder;
+pub(super) mod bound_array_expression;
 pub(super) mod bound_assignment_expression;
 pub(super) mod bound_binary_expression;
-mod bound_binary_operator;
+pub(super) mod bound_binary_operator;
 pub(super) mod bound_binary_operator_kind;
 pub(super) mod bound_block_statement;
+pub(super) mod bound_call_expression;
+pub(super) mod bound_compound_assignment_expression;
+pub(super) mod bound_conditional_expression;
+pub(super) mod bound_conditional_goto_statement;
+pub(super) mod bound_conversion_expression;
+pub(super) mod bound_do_while_statement;
 pub(super) mod bound_expression;
 pub(super) mod bound_expression_statement;
+pub(super) mod bound_field_assignment_expression;
+pub(super) mod bound_field_expression;
 pub(super) mod bound_for_statement;
 pub(super) mod bound_global_scope;
+pub(super) mod bound_goto_statement;
 pub(super) mod bound_if_statement;
+pub(super) mod bound_index_assignment_expression;
+pub(super) mod bound_index_expression;
+pub(super) mod bound_label;
+pub(super) mod bound_label_statement;
 pub(super) mod bound_literal_expression;
+pub(super) mod bound_return_statement;
 mod bound_scope;
 pub(super) mod bound_statement;
+pub(super) mod bound_step_expression;
+pub(super) mod bound_struct_expression;
 pub(super) mod bound_unary_expression;
 mod bound_unary_operator;
 pub(super) mod bound_unary_operator_kind;
 pub(super) mod bound_variable_declaration;
 pub(super) mod bound_variable_expression;
 pub(super) mod bound_while_statement;
+pub(super) mod conversion;
@@ -1,75 +1,439 @@
 use parking_lot::RwLock;
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use crate::{
     code_analysis::syntax::expression_syntax::ExpressionSyntax,
     code_analysis::syntax::parenthesized_expression_syntax::ParenthesizedExpressionSyntax,
     code_analysis::{
+        built_in_functions,
+        compilation_options::CompilationOptions,
         diagnostic::Diagnostic,
         diagnostic_bag::DiagnosticBag,
+        evaluator::Evaluator,
+        function_symbol::FunctionSymbol,
         minsk_type::MinskType,
         minsk_value::MinskValue,
+        parameter_symbol::ParameterSymbol,
+        runtime_error::{RuntimeError, RuntimeErrorKind},
+        struct_symbol::{StructDeclaration, StructSymbol},
         syntax::assignment_expression_syntax::AssignmentExpressionSyntax,
         syntax::{
+            array_expression_syntax::ArrayExpressionSyntax,
             binary_expression_syntax::BinaryExpressionSyntax,
-            block_statement_syntax::BlockStatementSyntax, compilation_unit::CompilationUnit,
+            block_statement_syntax::BlockStatementSyntax,
+            break_statement_syntax::BreakStatementSyntax,
+            call_expression_syntax::CallExpressionSyntax, compilation_unit::CompilationUnit,
+            conditional_expression_syntax::ConditionalExpressionSyntax,
+            continue_statement_syntax::ContinueStatementSyntax,
+            do_while_statement_syntax::DoWhileStatementSyntax,
             expression_statement_syntax::ExpressionStatementSyntax,
-            for_statement_syntax::ForStatementSyntax, if_statement_syntax::IfStatementSyntax,
-            name_expression_syntax::NameExpressionSyntax, statement_syntax::StatementSyntax,
-            syntax_kind::SyntaxKind, unary_expression_syntax::UnaryExpressionSyntax,
+            for_statement_syntax::ForStatementSyntax,
+            function_declaration_syntax::FunctionDeclarationSyntax,
+            if_statement_syntax::IfStatementSyntax,
+            increment_expression_syntax::IncrementExpressionSyntax,
+            index_assignment_expression_syntax::IndexAssignmentExpressionSyntax,
+            index_expression_syntax::IndexExpressionSyntax,
+            member_access_expression_syntax::MemberAccessExpressionSyntax,
+            member_assignment_expression_syntax::MemberAssignmentExpressionSyntax,
+            member_syntax::MemberSyntax, name_expression_syntax::NameExpressionSyntax,
+            return_statement_syntax::ReturnStatementSyntax, statement_syntax::StatementSyntax,
+            struct_declaration_syntax::StructDeclarationSyntax,
+            struct_expression_syntax::StructExpressionSyntax, syntax_facts::SyntaxFacts,
+            syntax_kind::SyntaxKind, syntax_token::SyntaxToken,
+            type_clause_syntax::TypeClauseSyntax, type_syntax::TypeSyntax,
+            unary_expression_syntax::UnaryExpressionSyntax,
             variable_declaration_syntax::VariableDeclarationSyntax,
             while_statement_syntax::WhileStatementSyntax,
         },
-        variable_symbol::VariableSymbol,
+        text::text_span::TextSpan,
+        variable_symbol::{VariableKind, VariableSymbol},
     },
 };
 
 use super::{
     super::syntax::literal_expression_syntax::LiteralExpressionSyntax,
+    bound_array_expression::BoundArrayExpression,
     bound_assignment_expression::BoundAssignmentExpression,
     bound_binary_expression::BoundBinaryExpression, bound_binary_operator::BoundBinaryOperator,
-    bound_block_statement::BoundBlockStatement, bound_expression::BoundExpression,
-    bound_expression_statement::BoundExpressionStatement, bound_for_statement::BoundForStatement,
-    bound_global_scope::BoundGlobalScope, bound_if_statement::BoundIfStatement,
-    bound_literal_expression::BoundLiteralExpression, bound_scope::BoundScope,
-    bound_statement::BoundStatement, bound_unary_expression::BoundUnaryExpression,
-    bound_unary_operator::BoundUnaryOperator, bound_variable_declaration::BoundVariableDeclaration,
+    bound_block_statement::BoundBlockStatement, bound_call_expression::BoundCallExpression,
+    bound_compound_assignment_expression::BoundCompoundAssignmentExpression,
+    bound_conditional_expression::BoundConditionalExpression,
+    bound_conversion_expression::BoundConversionExpression,
+    bound_do_while_statement::BoundDoWhileStatement, bound_expression::BoundExpression,
+    bound_expression_statement::BoundExpressionStatement,
+    bound_field_assignment_expression::BoundFieldAssignmentExpression,
+    bound_field_expression::BoundFieldExpression, bound_for_statement::BoundForStatement,
+    bound_global_scope::BoundGlobalScope, bound_goto_statement::BoundGotoStatement,
+    bound_if_statement::BoundIfStatement,
+    bound_index_assignment_expression::BoundIndexAssignmentExpression,
+    bound_index_expression::BoundIndexExpression, bound_label::BoundLabel,
+    bound_literal_expression::BoundLiteralExpression, bound_return_statement::BoundReturnStatement,
+    bound_scope::BoundScope, bound_statement::BoundStatement,
+    bound_step_expression::BoundStepExpression, bound_struct_expression::BoundStructExpression,
+    bound_unary_expression::BoundUnaryExpression, bound_unary_operator::BoundUnaryOperator,
+    bound_variable_declaration::BoundVariableDeclaration,
     bound_variable_expression::BoundVariableExpression, bound_while_statement::BoundWhileStatement,
+    conversion::Conversion,
 };
 
 pub struct Binder {
+    options: CompilationOptions,
     scope: Arc<RwLock<BoundScope>>,
     diagnostics: DiagnosticBag,
+    function: Option<FunctionSymbol>,
+    /// The type of the first `return` at global scope, which every other one has to match.
+    script_return_type: Option<MinskType>,
+    /// The break and continue labels of the loops enclosing the statement being bound.
+    loop_stack: Vec<(BoundLabel, BoundLabel)>,
+    label_count: usize,
 }
 
 impl Binder {
-    pub(crate) fn new(parent: Option<Arc<RwLock<BoundScope>>>) -> Self {
+    pub(crate) fn new(
+        options: CompilationOptions,
+        parent: Option<Arc<RwLock<BoundScope>>>,
+        function: Option<FunctionSymbol>,
+    ) -> Self {
+        let mut scope = BoundScope::new(parent);
+        if let Some(function) = &function {
+            for parameter in function.parameters() {
+                scope.try_declare(parameter.variable());
+            }
+        }
+
         Self {
-            scope: Arc::new(RwLock::new(BoundScope::new(parent))),
+            options,
+            scope: Arc::new(RwLock::new(scope)),
             diagnostics: DiagnosticBag::new(),
+            function,
+            script_return_type: None,
+            loop_stack: vec![],
+            label_count: 0,
         }
     }
 
     pub(crate) fn bind_global_scope(
+        options: CompilationOptions,
         previous: Option<Arc<BoundGlobalScope>>,
         syntax: &CompilationUnit,
     ) -> BoundGlobalScope {
         let parent_scope = Self::create_parent_scopes(previous.clone());
-        let mut binder = Binder::new(parent_scope);
-        let statement = binder.bind_statement(syntax.statement());
+        let mut binder = Binder::new(options, parent_scope, None);
+
+        // structs come first, so that any function or statement can use them
+        let structs = syntax
+            .members()
+            .iter()
+            .filter_map(|m| match m {
+                MemberSyntax::Struct(s) => Some(s.as_ref()),
+                MemberSyntax::Function(_) | MemberSyntax::GlobalStatement(_) => None,
+            })
+            .collect::<Vec<_>>();
+        binder.bind_struct_declarations(&structs);
+
+        // declare every function up front so that calls can appear before declarations
+        let mut declarations = syntax
+            .members()
+            .iter()
+            .filter_map(|m| match m {
+                MemberSyntax::Function(f) => Some(binder.bind_function_declaration(f)),
+                MemberSyntax::GlobalStatement(_) | MemberSyntax::Struct(_) => None,
+            })
+            .collect::<Vec<_>>()
+            .into_iter();
+
+        // members are bound in source order, so that a function body only sees the globals
+        // declared before it. global statements share the global scope rather than opening
+        // a block of their own
+        let mut statements = vec![];
+        let mut functions = HashMap::new();
+        for member in syntax.members() {
+            match member {
+                MemberSyntax::GlobalStatement(g) => {
+                    statements.push(binder.bind_statement(g.statement()))
+                }
+                MemberSyntax::Function(f) => {
+                    if let Some(function) = declarations.next().unwrap() {
+                        let body = binder.bind_function_body(&function, f);
+                        functions.insert(function, body);
+                    }
+                }
+                MemberSyntax::Struct(_) => {}
+            }
+        }
+        let statement = BoundStatement::Block(BoundBlockStatement::new(statements));
         let variables = binder
             .scope
             .read()
             .declared_variables()
             .cloned()
             .collect::<Vec<_>>();
+        let types = binder
+            .scope
+            .read()
+            .declared_types()
+            .map(|(name, ty)| (name.clone(), *ty))
+            .collect::<Vec<_>>();
+
         let mut diagnostics = binder.diagnostics().collect::<Vec<_>>();
 
         if let Some(previous) = &previous {
             diagnostics.append(&mut previous.diagnostics().collect::<Vec<_>>());
         }
 
-        BoundGlobalScope::new(previous, diagnostics, variables, statement)
+        BoundGlobalScope::new(
+            previous,
+            diagnostics,
+            variables,
+            functions,
+            types,
+            statement,
+        )
+    }
+
+    fn bind_function_body(
+        &mut self,
+        function: &FunctionSymbol,
+        syntax: &FunctionDeclarationSyntax,
+    ) -> BoundBlockStatement {
+        let mut function_binder = Binder::new(
+            self.options,
+            Some(self.scope.clone()),
+            Some(function.clone()),
+        );
+        let body = function_binder.bind_block_statement(syntax.body());
+        if function.ty() != MinskType::Null && !Self::always_returns(&body) {
+            function_binder
+                .diagnostics
+                .report_not_all_code_paths_return(syntax.identifier().span);
+        }
+        self.diagnostics.extend(function_binder.diagnostics.iter());
+        match body {
+            BoundStatement::Block(body) => body,
+            _ => unreachable!("a block statement binds to a block"),
+        }
+    }
+
+    /// Declares every struct before binding any fields, so that fields can use them all. A
+    /// submission declaring the same structs as an earlier one, as when a script is run
+    /// again in the REPL, gets the earlier symbols back rather than leaking new ones.
+    fn bind_struct_declarations(&mut self, syntax: &[&StructDeclarationSyntax]) {
+        let previous = self.previous_structs(syntax);
+        let reused = previous.is_some();
+        let symbols = syntax
+            .iter()
+            .enumerate()
+            .map(|(i, s)| self.declare_struct(s, previous.as_ref().map(|p| p[i])))
+            .collect::<Vec<_>>();
+        for (symbol, s) in symbols.into_iter().zip(syntax) {
+            self.bind_struct_declaration(symbol, reused, s);
+        }
+    }
+
+    /// The symbols of the last identical declarations, if there are some for every struct
+    /// and their fields still have the types the declarations name.
+    fn previous_structs(
+        &self,
+        syntax: &[&StructDeclarationSyntax],
+    ) -> Option<Vec<&'static StructSymbol>> {
+        let previous = syntax
+            .iter()
+            .map(|s| StructSymbol::previous(&Self::struct_declaration(s)))
+            .collect::<Option<Vec<_>>>()?;
+        let declared = syntax
+            .iter()
+            .zip(&previous)
+            .map(|(s, &p)| (s.identifier().text.as_str(), MinskType::Struct(p)))
+            .collect::<HashMap<_, _>>();
+        let unchanged = syntax.iter().zip(&previous).all(|(s, p)| {
+            p.fields().len() == s.fields().len()
+                && s.fields()
+                    .iter()
+                    .zip(p.fields())
+                    .all(|(field, (name, ty))| {
+                        field.identifier().text == *name
+                            && self.resolve_type(field.type_clause().ty(), &declared) == Some(*ty)
+                    })
+        });
+        unchanged.then_some(previous)
+    }
+
+    fn struct_declaration(syntax: &StructDeclarationSyntax) -> StructDeclaration {
+        let fields = syntax
+            .fields()
+            .iter()
+            .map(|f| {
+                let ty = Self::type_name(f.type_clause().ty());
+                (f.identifier().text.clone(), ty)
+            })
+            .collect();
+        (syntax.identifier().text.clone(), fields)
+    }
+
+    fn type_name(syntax: &TypeSyntax) -> String {
+        match syntax {
+            TypeSyntax::Name(identifier) => identifier.text.clone(),
+            TypeSyntax::Array(a) => format!("[{}]", Self::type_name(a.element_type())),
+        }
+    }
+
+    /// Like `bind_type`, with `declared` standing in for the types declared so far, and
+    /// without reporting anything.
+    fn resolve_type(
+        &self,
+        syntax: &TypeSyntax,
+        declared: &HashMap<&str, MinskType>,
+    ) -> Option<MinskType> {
+        match syntax {
+            TypeSyntax::Name(identifier) => Self::lookup_type(&identifier.text)
+                .or_else(|| declared.get(identifier.text.as_str()).copied())
+                .or_else(|| self.scope.read().try_lookup_type(&identifier.text)),
+            TypeSyntax::Array(a) => self
+                .resolve_type(a.element_type(), declared)
+                .map(MinskType::array),
+        }
+    }
+
+    /// `None` when the name is missing or already taken, which binding the fields reports.
+    fn declare_struct(
+        &mut self,
+        syntax: &StructDeclarationSyntax,
+        previous: Option<&'static StructSymbol>,
+    ) -> Option<&'static StructSymbol> {
+        let name = &syntax.identifier().text;
+        let declared = self.scope.read().declared_types().any(|(n, _)| n == name);
+        if name.is_empty() || declared || Self::lookup_type(name).is_some() {
+            return None;
+        }
+        let symbol =
+            previous.unwrap_or_else(|| StructSymbol::declare(Self::struct_declaration(syntax)));
+        self.scope
+            .write()
+            .try_declare_type(name, MinskType::Struct(symbol));
+        Some(symbol)
+    }
+
+    /// A reused symbol already has these fields, but binding them still reports any errors.
+    fn bind_struct_declaration(
+        &mut self,
+        symbol: Option<&'static StructSymbol>,
+        reused: bool,
+        syntax: &StructDeclarationSyntax,
+    ) {
+        let mut fields = Vec::<(String, MinskType)>::new();
+        for field in syntax.fields().iter() {
+            let name = &field.identifier().text;
+            let ty = self
+                .bind_type_clause(Some(field.type_clause()))
+                .unwrap_or(MinskType::Null);
+            if fields.iter().any(|(n, _)| n == name) {
+                self.diagnostics
+                    .report_field_already_declared(field.span(), name);
+            } else {
+                fields.push((name.clone(), ty));
+            }
+        }
+
+        let name = &syntax.identifier().text;
+        if name.is_empty() {
+            // the parser already reported the missing name
+            return;
+        }
+        if fields.is_empty() {
+            self.diagnostics
+                .report_empty_struct(syntax.identifier().span, name);
+        }
+        match symbol {
+            Some(_) if reused => {}
+            Some(symbol) => symbol.define_fields(fields),
+            None => self
+                .diagnostics
+                .report_type_already_declared(syntax.identifier().span, name),
+        }
+    }
+
+    fn bind_function_declaration(
+        &mut self,
+        syntax: &FunctionDeclarationSyntax,
+    ) -> Option<FunctionSymbol> {
+        let mut parameters = Vec::<ParameterSymbol>::new();
+        for parameter in syntax.parameters().iter() {
+            let name = &parameter.identifier().text;
+            let ty = self
+                .bind_type_clause(Some(parameter.type_clause()))
+                .unwrap_or(MinskType::Null);
+            if parameters.iter().any(|p| p.name() == name) {
+                self.diagnostics
+                    .report_parameter_already_declared(parameter.span(), name);
+            } else {
+                parameters.push(ParameterSymbol::new(name.clone(), ty));
+            }
+        }
+
+        let ty = self
+            .bind_type_clause(syntax.type_clause())
+            .unwrap_or(MinskType::Null);
+        let name = &syntax.identifier().text;
+        // a call with a type's name is always a conversion
+        if Self::lookup_type(name).is_some() {
+            self.diagnostics
+                .report_function_named_like_type(syntax.identifier().span, name);
+            return None;
+        }
+        let function = FunctionSymbol::new(name.clone(), parameters, ty);
+        if !self.scope.write().try_declare_function(function.clone()) {
+            self.diagnostics
+                .report_function_already_declared(syntax.identifier().span, name);
+            return None;
+        }
+        Some(function)
+    }
+
+    fn bind_type_clause(&mut self, syntax: Option<&TypeClauseSyntax>) -> Option<MinskType> {
+        self.bind_type(syntax?.ty())
+    }
+
+    fn bind_type(&mut self, syntax: &TypeSyntax) -> Option<MinskType> {
+        match syntax {
+            TypeSyntax::Name(identifier) => {
+                let ty = Self::lookup_type(&identifier.text)
+                    .or_else(|| self.scope.read().try_lookup_type(&identifier.text));
+                if ty.is_none() && !identifier.text.is_empty() {
+                    self.diagnostics
+                        .report_undefined_type(identifier.span, &identifier.text);
+                }
+                ty
+            }
+            TypeSyntax::Array(a) => self.bind_type(a.element_type()).map(MinskType::array),
+        }
+    }
+
+    fn lookup_type(name: &str) -> Option<MinskType> {
+        match name {
+            "int8" => Some(MinskType::Int8),
+            "int16" => Some(MinskType::Int16),
+            "int" => Some(MinskType::Integer),
+            "uint" => Some(MinskType::UInt),
+            "int64" => Some(MinskType::Int64),
+            "float" => Some(MinskType::Float),
+            "bool" => Some(MinskType::Boolean),
+            "char" => Some(MinskType::Char),
+            "string" => Some(MinskType::String),
+            _ => None,
+        }
+    }
+
+    /// Whether every path through the statement ends in a `return`.
+    fn always_returns(statement: &BoundStatement) -> bool {
+        match statement {
+            BoundStatement::Return(_) => true,
+            BoundStatement::Block(b) => b.statements().iter().any(Self::always_returns),
+            BoundStatement::If(i) => {
+                Self::always_returns(i.then_statement())
+                    && i.else_statement().is_some_and(Self::always_returns)
+            }
+            _ => false,
+        }
     }
 
     pub(crate) fn create_parent_scopes(
@@ -82,19 +446,32 @@ impl Binder {
             previous = previous.and_then(|p| p.previous().clone());
         }
 
-        let mut parent: Option<Arc<RwLock<BoundScope>>> = None;
-        while !stack.is_empty() {
-            let previous = stack.pop().unwrap();
+        let mut parent = Some(Self::create_root_scope());
+        while let Some(previous) = stack.pop() {
             let mut scope = BoundScope::new(parent);
             for v in previous.variables() {
                 scope.try_declare(v);
             }
+            for f in previous.functions() {
+                scope.try_declare_function(f);
+            }
+            for (name, ty) in previous.types() {
+                scope.try_declare_type(name, ty);
+            }
 
             parent = Some(Arc::new(RwLock::new(scope)));
         }
         parent
     }
 
+    fn create_root_scope() -> Arc<RwLock<BoundScope>> {
+        let mut scope = BoundScope::new(None);
+        for function in built_in_functions::all() {
+            scope.try_declare_function(function);
+        }
+        Arc::new(RwLock::new(scope))
+    }
+
     pub fn diagnostics(&self) -> impl Iterator<Item = Diagnostic> + '_ {
         self.diagnostics.iter()
     }
@@ -102,44 +479,153 @@ impl Binder {
     fn bind_statement(&mut self, syntax: &StatementSyntax) -> BoundStatement {
         match syntax {
             StatementSyntax::Block(b) => self.bind_block_statement(b),
+            StatementSyntax::Break(b) => self.bind_break_statement(b),
+            StatementSyntax::Continue(c) => self.bind_continue_statement(c),
+            StatementSyntax::DoWhile(d) => self.bind_do_while_statement(d),
             StatementSyntax::Expression(e) => self.bind_expression_statement(e),
             StatementSyntax::If(i) => self.bind_if_statement(i),
             StatementSyntax::VariableDeclaration(v) => self.bind_variable_declaration(v),
             StatementSyntax::While(w) => self.bind_while_statement(w),
             StatementSyntax::For(f) => self.bind_for_statement(f),
+            StatementSyntax::Return(r) => self.bind_return_statement(r),
         }
     }
 
+    fn declare_variable(
+        &mut self,
+        identifier: &SyntaxToken,
+        read_only: bool,
+        ty: MinskType,
+    ) -> VariableSymbol {
+        let name = &identifier.text;
+        let kind = if self.function.is_some() {
+            VariableKind::Local
+        } else {
+            VariableKind::Global
+        };
+        let variable = VariableSymbol::new(name.clone(), read_only, ty, kind);
+        if !self.scope.write().try_declare(variable.clone()) {
+            self.diagnostics
+                .report_variable_already_declared(identifier.span, name);
+        }
+        variable
+    }
+
     fn bind_for_statement(&mut self, syntax: &ForStatementSyntax) -> BoundStatement {
-        let lower_bound = self.bind_expression_with_type(syntax.lower_bound(), MinskType::Integer);
-        let upper_bound = self.bind_expression_with_type(syntax.upper_bound(), MinskType::Integer);
+        // a loop counts through ints, or through chars when it starts at one
+        let lower_bound = self.bind_expression(syntax.lower_bound());
+        let ty = match lower_bound.ty() {
+            MinskType::Char => MinskType::Char,
+            _ => MinskType::Integer,
+        };
+        let lower_bound = self.bind_conversion(syntax.lower_bound().span(), lower_bound, ty, false);
+        let upper_bound = self.bind_expression_with_type(syntax.upper_bound(), ty);
+        let step = syntax.step_clause().map(|s| self.bind_step(s.step()));
 
         self.scope = Arc::new(RwLock::new(BoundScope::new(Some(self.scope.clone()))));
 
-        let name = syntax.identifier().text.clone();
-        let variable = VariableSymbol::new(name.clone(), true, MinskType::Integer);
-        if !self.scope.write().try_declare(variable.clone()) {
-            self.diagnostics
-                .report_variable_already_declared(syntax.identifier().span, &name);
-        }
+        let variable = self.declare_variable(syntax.identifier(), true, ty);
 
-        let body = self.bind_statement(syntax.body());
+        let (body, break_label, continue_label) = self.bind_loop_body(syntax.body());
 
         let parent = self.scope.read().parent().unwrap();
         self.scope = parent;
 
-        BoundStatement::For(BoundForStatement::new(
+        BoundStatement::For(Box::new(BoundForStatement::new(
             variable,
             lower_bound,
             upper_bound,
+            step,
             Box::new(body),
-        ))
+            break_label,
+            continue_label,
+            syntax.span(),
+        )))
+    }
+
+    /// Steps are ints, even in a loop over chars. A constant step is checked for zero here,
+    /// any other step when the loop starts.
+    fn bind_step(&mut self, syntax: &ExpressionSyntax) -> BoundExpression {
+        let step = self.bind_expression_with_type(syntax, MinskType::Integer);
+        match step {
+            BoundExpression::Literal(BoundLiteralExpression {
+                value: MinskValue::Integer(0),
+            }) => {
+                self.diagnostics.report_zero_step(syntax.span());
+                step
+            }
+            BoundExpression::Literal(_) => step,
+            _ => BoundExpression::Step(BoundStepExpression {
+                expression: Box::new(step),
+                span: syntax.span(),
+            }),
+        }
     }
 
     fn bind_while_statement(&mut self, syntax: &WhileStatementSyntax) -> BoundStatement {
         let condition = self.bind_expression_with_type(syntax.condition(), MinskType::Boolean);
-        let body = self.bind_statement(syntax.body());
-        BoundStatement::While(BoundWhileStatement::new(condition, Box::new(body)))
+        let (body, break_label, continue_label) = self.bind_loop_body(syntax.body());
+        BoundStatement::While(BoundWhileStatement::new(
+            condition,
+            Box::new(body),
+            break_label,
+            continue_label,
+        ))
+    }
+
+    fn bind_do_while_statement(&mut self, syntax: &DoWhileStatementSyntax) -> BoundStatement {
+        let (body, break_label, continue_label) = self.bind_loop_body(syntax.body());
+        let condition = self.bind_expression_with_type(syntax.condition(), MinskType::Boolean);
+        BoundStatement::DoWhile(BoundDoWhileStatement::new(
+            Box::new(body),
+            condition,
+            break_label,
+            continue_label,
+        ))
+    }
+
+    fn bind_loop_body(
+        &mut self,
+        body: &StatementSyntax,
+    ) -> (BoundStatement, BoundLabel, BoundLabel) {
+        self.label_count += 1;
+        let break_label = BoundLabel::new(format!("break{}", self.label_count));
+        let continue_label = BoundLabel::new(format!("continue{}", self.label_count));
+
+        self.loop_stack
+            .push((break_label.clone(), continue_label.clone()));
+        let body = self.bind_statement(body);
+        self.loop_stack.pop();
+
+        (body, break_label, continue_label)
+    }
+
+    fn bind_break_statement(&mut self, syntax: &BreakStatementSyntax) -> BoundStatement {
+        match self.loop_stack.last() {
+            Some((break_label, _)) => {
+                BoundStatement::Goto(BoundGotoStatement::new(break_label.clone()))
+            }
+            None => self.bind_invalid_break_or_continue(syntax.keyword()),
+        }
+    }
+
+    fn bind_continue_statement(&mut self, syntax: &ContinueStatementSyntax) -> BoundStatement {
+        match self.loop_stack.last() {
+            Some((_, continue_label)) => {
+                BoundStatement::Goto(BoundGotoStatement::new(continue_label.clone()))
+            }
+            None => self.bind_invalid_break_or_continue(syntax.keyword()),
+        }
+    }
+
+    fn bind_invalid_break_or_continue(&mut self, keyword: &SyntaxToken) -> BoundStatement {
+        self.diagnostics
+            .report_invalid_break_or_continue(keyword.span, &keyword.text);
+        BoundStatement::Expression(BoundExpressionStatement::new(BoundExpression::Literal(
+            BoundLiteralExpression {
+                value: MinskValue::Integer(0),
+            },
+        )))
     }
 
     fn bind_if_statement(&mut self, syntax: &IfStatementSyntax) -> BoundStatement {
@@ -155,16 +641,61 @@ impl Binder {
         ))
     }
 
-    fn bind_variable_declaration(&mut self, syntax: &VariableDeclarationSyntax) -> BoundStatement {
-        let name = &syntax.identifier().text;
-        let read_only = syntax.keyword_token().kind == SyntaxKind::LetKeyword;
-        let initializer = self.bind_expression(syntax.initializer());
-        let variable = VariableSymbol::new(name.to_string(), read_only, initializer.ty());
+    fn bind_return_statement(&mut self, syntax: &ReturnStatementSyntax) -> BoundStatement {
+        let function = match &self.function {
+            Some(f) => f.clone(),
+            None => return self.bind_script_return_statement(syntax),
+        };
 
-        if !self.scope.write().try_declare(variable.clone()) {
-            self.diagnostics
-                .report_variable_already_declared(syntax.identifier().span, &name);
+        let expression = match syntax.expression() {
+            Some(e) if function.ty() == MinskType::Null => {
+                self.diagnostics
+                    .report_invalid_return_expression(e.span(), function.name());
+                Some(self.bind_expression(e))
+            }
+            Some(e) => Some(self.bind_expression_with_type(e, function.ty())),
+            None => {
+                if function.ty() != MinskType::Null {
+                    self.diagnostics
+                        .report_missing_return_expression(syntax.span(), function.ty());
+                }
+                None
+            }
+        };
+        BoundStatement::Return(BoundReturnStatement::new(expression))
+    }
+
+    /// A `return` at global scope, which ends the script with its value as the result.
+    fn bind_script_return_statement(&mut self, syntax: &ReturnStatementSyntax) -> BoundStatement {
+        let expression = syntax.expression().map(|e| match self.script_return_type {
+            Some(ty) if ty != MinskType::Null => self.bind_expression_with_type(e, ty),
+            _ => self.bind_expression(e),
+        });
+        let ty = expression
+            .as_ref()
+            .map_or(MinskType::Null, BoundExpression::ty);
+        match self.script_return_type {
+            None => self.script_return_type = Some(ty),
+            Some(MinskType::Null) if ty != MinskType::Null => self
+                .diagnostics
+                .report_unexpected_return_expression(syntax.expression().unwrap().span()),
+            Some(expected) if expected != MinskType::Null && expression.is_none() => self
+                .diagnostics
+                .report_missing_return_expression(syntax.span(), expected),
+            Some(_) => {}
         }
+        BoundStatement::Return(BoundReturnStatement::new(expression))
+    }
+
+    fn bind_variable_declaration(&mut self, syntax: &VariableDeclarationSyntax) -> BoundStatement {
+        let read_only = syntax.keyword_token().kind == SyntaxKind::LetKeyword;
+        let declared_type = self.bind_type_clause(syntax.type_clause());
+        let initializer = match declared_type {
+            Some(ty) => self.bind_expression_with_type(syntax.initializer(), ty),
+            None => self.bind_expression(syntax.initializer()),
+        };
+        let ty = declared_type.unwrap_or_else(|| initializer.ty());
+        let variable = self.declare_variable(syntax.identifier(), read_only, ty);
 
         BoundStatement::VariableDeclaration(BoundVariableDeclaration::new(variable, initializer))
     }
@@ -192,12 +723,85 @@ impl Binder {
         syntax: &ExpressionSyntax,
         ty: MinskType,
     ) -> BoundExpression {
-        let result = self.bind_expression(syntax);
-        if result.ty() != ty {
+        // an empty array takes its type from the context, e.g. `var a: [int] = []`
+        if let (ExpressionSyntax::Array(a), Some(_)) = (syntax, ty.element_type()) {
+            if a.elements.len() == 0 {
+                return BoundExpression::Array(BoundArrayExpression {
+                    ty,
+                    elements: Vec::new(),
+                });
+            }
+        }
+        let expression = self.bind_expression(syntax);
+        self.bind_conversion(syntax.span(), expression, ty, false)
+    }
+
+    fn bind_conversion(
+        &mut self,
+        span: TextSpan,
+        expression: BoundExpression,
+        ty: MinskType,
+        allow_explicit: bool,
+    ) -> BoundExpression {
+        let conversion = Self::classify_conversion(&expression, ty);
+        if !conversion.exists() {
+            self.diagnostics
+                .report_cannot_convert(span, expression.ty(), ty);
+            return expression;
+        }
+        if conversion == Conversion::Explicit && !allow_explicit {
             self.diagnostics
-                .report_cannot_convert(syntax.span(), result.ty(), ty);
+                .report_cannot_convert_implicitly(span, expression.ty(), ty);
+            return expression;
+        }
+        if conversion == Conversion::Identity {
+            return expression;
+        }
+
+        if let BoundExpression::Literal(l) = &expression {
+            let result = Evaluator::evaluate_conversion(
+                l.value.clone(),
+                ty,
+                span,
+                self.options.checked_arithmetic,
+            );
+            if let Some(value) = self.fold_result(result) {
+                return BoundExpression::Literal(BoundLiteralExpression { value });
+            }
+        }
+        BoundExpression::Conversion(BoundConversionExpression {
+            ty,
+            expression: Box::new(expression),
+            span,
+        })
+    }
+
+    /// Like [`Conversion::classify`], but an integer constant also converts implicitly to
+    /// any integral type it fits in, e.g. `var b: int8 = 100`.
+    fn classify_conversion(expression: &BoundExpression, ty: MinskType) -> Conversion {
+        let conversion = Conversion::classify(expression.ty(), ty);
+        match expression {
+            BoundExpression::Literal(l)
+                if conversion == Conversion::Explicit
+                    && ty.is_integral()
+                    && l.value
+                        .as_integral()
+                        .and_then(|i| MinskValue::integral(ty, i))
+                        .is_some() =>
+            {
+                Conversion::Implicit
+            }
+            _ => conversion,
+        }
+    }
+
+    /// The number of implicit conversions needed to pass `expression` as a `ty`.
+    fn conversion_cost(expression: &BoundExpression, ty: MinskType) -> Option<usize> {
+        match Self::classify_conversion(expression, ty) {
+            Conversion::Identity => Some(0),
+            Conversion::Implicit => Some(1),
+            _ => None,
         }
-        result
     }
 
     fn bind_expression(&mut self, syntax: &ExpressionSyntax) -> BoundExpression {
@@ -208,6 +812,210 @@ impl Binder {
             ExpressionSyntax::Parenthesized(p) => self.bind_parenthesized_expression(p),
             ExpressionSyntax::Name(n) => self.bind_name_expression(n),
             ExpressionSyntax::Assignment(a) => self.bind_assignment_expression(a),
+            ExpressionSyntax::Call(c) => self.bind_call_expression(c),
+            ExpressionSyntax::Increment(i) => self.bind_increment_expression(i),
+            ExpressionSyntax::Array(a) => self.bind_array_expression(a),
+            ExpressionSyntax::Index(i) => match self.bind_index_expression(i) {
+                Some(i) => BoundExpression::Index(i),
+                None => BoundExpression::Literal(BoundLiteralExpression {
+                    value: MinskValue::Integer(0),
+                }),
+            },
+            ExpressionSyntax::IndexAssignment(a) => self.bind_index_assignment_expression(a),
+            ExpressionSyntax::Struct(s) => self.bind_struct_expression(s),
+            ExpressionSyntax::MemberAccess(m) => match self.bind_member_access_expression(m) {
+                Some(f) => BoundExpression::Field(f),
+                None => BoundExpression::Literal(BoundLiteralExpression {
+                    value: MinskValue::Integer(0),
+                }),
+            },
+            ExpressionSyntax::MemberAssignment(a) => self.bind_member_assignment_expression(a),
+            ExpressionSyntax::Conditional(c) => self.bind_conditional_expression(c),
+        }
+    }
+
+    fn bind_conditional_expression(
+        &mut self,
+        syntax: &ConditionalExpressionSyntax,
+    ) -> BoundExpression {
+        let condition = self.bind_expression_with_type(&syntax.condition, MinskType::Boolean);
+        let when_true = self.bind_expression(&syntax.when_true);
+        let when_false = self.bind_expression(&syntax.when_false);
+        // like arrays, the branches may meet at a wider type, e.g. `c ? 1 : 2.5` is a Float
+        let ty = [when_true.ty(), when_false.ty()]
+            .iter()
+            .copied()
+            .find(|&ty| {
+                Self::conversion_cost(&when_true, ty).is_some()
+                    && Self::conversion_cost(&when_false, ty).is_some()
+            });
+        let ty = match ty {
+            Some(ty) => ty,
+            None => {
+                self.diagnostics.report_mismatched_branch_types(
+                    syntax.span(),
+                    when_true.ty(),
+                    when_false.ty(),
+                );
+                return when_true;
+            }
+        };
+        let when_true = self.bind_conversion(syntax.when_true.span(), when_true, ty, false);
+        let when_false = self.bind_conversion(syntax.when_false.span(), when_false, ty, false);
+
+        // a constant condition picks its branch up front
+        if let BoundExpression::Literal(l) = &condition {
+            return match l.value.as_boolean() {
+                Some(true) => when_true,
+                Some(false) => when_false,
+                // an error placeholder, which has already been reported
+                None => when_true,
+            };
+        }
+        BoundExpression::Conditional(BoundConditionalExpression {
+            condition: Box::new(condition),
+            when_true: Box::new(when_true),
+            when_false: Box::new(when_false),
+        })
+    }
+
+    fn bind_array_expression(&mut self, syntax: &ArrayExpressionSyntax) -> BoundExpression {
+        let elements = syntax
+            .elements
+            .iter()
+            .map(|e| self.bind_expression(e))
+            .collect::<Vec<_>>();
+        // the first element type that every element converts to, e.g. `[1, 2.5]` is a [Float]
+        let element_type = match elements.iter().map(BoundExpression::ty).find(|&ty| {
+            elements
+                .iter()
+                .all(|e| Self::conversion_cost(e, ty).is_some())
+        }) {
+            Some(ty) => ty,
+            None if elements.is_empty() => {
+                self.diagnostics
+                    .report_cannot_infer_empty_array(syntax.span());
+                MinskType::Integer
+            }
+            None => elements[0].ty(),
+        };
+        let elements = elements
+            .into_iter()
+            .zip(syntax.elements.iter())
+            .map(|(e, s)| self.bind_conversion(s.span(), e, element_type, false))
+            .collect();
+        BoundExpression::Array(BoundArrayExpression {
+            ty: MinskType::array(element_type),
+            elements,
+        })
+    }
+
+    fn bind_index_expression(
+        &mut self,
+        syntax: &IndexExpressionSyntax,
+    ) -> Option<BoundIndexExpression> {
+        let array = self.bind_expression(&syntax.expression);
+        let index = self.bind_expression_with_type(&syntax.index, MinskType::Integer);
+        if array.ty().element_type().is_none() {
+            self.diagnostics
+                .report_cannot_index(syntax.expression.span(), array.ty());
+            return None;
+        }
+        Some(BoundIndexExpression {
+            array: Box::new(array),
+            index: Box::new(index),
+            index_span: syntax.index.span(),
+        })
+    }
+
+    fn bind_struct_expression(&mut self, syntax: &StructExpressionSyntax) -> BoundExpression {
+        let name = &syntax.identifier_token.text;
+        let ty = self.scope.read().try_lookup_type(name);
+        let symbol = match ty {
+            Some(MinskType::Struct(symbol)) => symbol,
+            _ => {
+                self.diagnostics
+                    .report_undefined_type(syntax.identifier_token.span, name);
+                for initializer in syntax.fields.iter() {
+                    self.bind_expression(&initializer.expression);
+                }
+                return BoundExpression::Literal(BoundLiteralExpression {
+                    value: MinskValue::Integer(0),
+                });
+            }
+        };
+        let ty = MinskType::Struct(symbol);
+
+        let mut fields = vec![None; symbol.fields().len()];
+        for initializer in syntax.fields.iter() {
+            let field_name = &initializer.identifier_token.text;
+            let (index, field_type) = match symbol.field(field_name) {
+                Some(field) => field,
+                None => {
+                    self.diagnostics.report_undefined_field(
+                        initializer.identifier_token.span,
+                        ty,
+                        field_name,
+                    );
+                    self.bind_expression(&initializer.expression);
+                    continue;
+                }
+            };
+            let bound = self.bind_expression_with_type(&initializer.expression, field_type);
+            if fields[index].is_some() {
+                self.diagnostics
+                    .report_field_already_initialized(initializer.span(), field_name);
+            } else {
+                fields[index] = Some(bound);
+            }
+        }
+
+        let fields = fields
+            .into_iter()
+            .zip(symbol.fields())
+            .map(|(field, (field_name, _))| {
+                field.unwrap_or_else(|| {
+                    self.diagnostics.report_missing_field_initializer(
+                        syntax.identifier_token.span,
+                        ty,
+                        field_name,
+                    );
+                    BoundExpression::Literal(BoundLiteralExpression {
+                        value: MinskValue::Integer(0),
+                    })
+                })
+            })
+            .collect();
+        BoundExpression::Struct(BoundStructExpression { ty, fields })
+    }
+
+    fn bind_member_access_expression(
+        &mut self,
+        syntax: &MemberAccessExpressionSyntax,
+    ) -> Option<BoundFieldExpression> {
+        let expression = self.bind_expression(&syntax.expression);
+        let name = &syntax.identifier_token.text;
+        let field = match expression.ty() {
+            MinskType::Struct(symbol) => symbol.field(name),
+            _ => None,
+        };
+        match field {
+            Some((index, ty)) => Some(BoundFieldExpression {
+                expression: Box::new(expression),
+                index,
+                ty,
+            }),
+            None => {
+                // an empty name was inserted by the parser, which already reported it
+                if !name.is_empty() {
+                    self.diagnostics.report_undefined_field(
+                        syntax.identifier_token.span,
+                        expression.ty(),
+                        name,
+                    );
+                }
+                None
+            }
         }
     }
 
@@ -221,11 +1029,21 @@ impl Binder {
 
     fn bind_unary_expression(&mut self, syntax: &UnaryExpressionSyntax) -> BoundExpression {
         let operand = self.bind_expression(&syntax.operand);
-        let operator = BoundUnaryOperator::bind(syntax.operator_token.kind, operand.ty());
+        // like binary operators, the operand may be widened, e.g. `-u` for a `uint` binds
+        // as `-int64(u)`
+        let operator = BoundUnaryOperator::operators()
+            .iter()
+            .filter(|op| op.syntax_kind == syntax.operator_token.kind)
+            .filter_map(|op| Some((Self::conversion_cost(&operand, op.operand_type)?, *op)))
+            .min_by_key(|(cost, _)| *cost)
+            .map(|(_, op)| op);
         if let Some(op) = operator {
-            BoundExpression::Unary(BoundUnaryExpression {
+            let operand =
+                self.bind_conversion(syntax.operand.span(), operand, op.operand_type, false);
+            self.fold_unary_expression(BoundUnaryExpression {
                 op,
                 operand: Box::new(operand),
+                operator_span: syntax.operator_token.span,
             })
         } else {
             self.diagnostics.report_undefined_unary_operator(
@@ -238,14 +1056,30 @@ impl Binder {
     }
 
     fn bind_binary_expression(&mut self, syntax: &BinaryExpressionSyntax) -> BoundExpression {
+        let kind = syntax.operator_token.kind;
         let left = self.bind_expression(&syntax.left);
         let right = self.bind_expression(&syntax.right);
-        let operator = BoundBinaryOperator::bind(syntax.operator_token.kind, left.ty(), right.ty());
+        // picks the operator needing the fewest implicit conversions, so mixed operands
+        // are promoted, e.g. `1 + 2.5` binds as `float(1) + 2.5`
+        let operator = BoundBinaryOperator::operators()
+            .iter()
+            .filter(|op| op.syntax_kind == kind)
+            .filter_map(|op| {
+                let cost = Self::conversion_cost(&left, op.left_type)?
+                    + Self::conversion_cost(&right, op.right_type)?;
+                Some((cost, *op))
+            })
+            .min_by_key(|(cost, _)| *cost)
+            .map(|(_, op)| op);
         if let Some(op) = operator {
-            BoundExpression::Binary(BoundBinaryExpression {
+            let left = self.bind_conversion(syntax.left.span(), left, op.left_type, false);
+            let right = self.bind_conversion(syntax.right.span(), right, op.right_type, false);
+            self.fold_binary_expression(BoundBinaryExpression {
                 left: Box::new(left),
                 op,
                 right: Box::new(right),
+                span: syntax.span(),
+                operator_span: syntax.operator_token.span,
             })
         } else {
             self.diagnostics.report_undefined_binary_operator(
@@ -258,6 +1092,56 @@ impl Binder {
         }
     }
 
+    /// Replaces an operation on a literal with its result.
+    fn fold_unary_expression(&mut self, u: BoundUnaryExpression) -> BoundExpression {
+        if let BoundExpression::Literal(operand) = u.operand.as_ref() {
+            let result = Evaluator::evaluate_unary_operator(
+                u.op.kind,
+                operand.value.clone(),
+                u.operator_span,
+                self.options.checked_arithmetic,
+            );
+            if let Some(value) = self.fold_result(result) {
+                return BoundExpression::Literal(BoundLiteralExpression { value });
+            }
+        }
+        BoundExpression::Unary(u)
+    }
+
+    /// Replaces an operation on literals with its result.
+    fn fold_binary_expression(&mut self, b: BoundBinaryExpression) -> BoundExpression {
+        if let (BoundExpression::Literal(left), BoundExpression::Literal(right)) =
+            (b.left.as_ref(), b.right.as_ref())
+        {
+            let result = Evaluator::evaluate_binary_operator(
+                b.op.kind,
+                left.value.clone(),
+                right.value.clone(),
+                b.span,
+                b.operator_span,
+                self.options.checked_arithmetic,
+            );
+            if let Some(value) = self.fold_result(result) {
+                return BoundExpression::Literal(BoundLiteralExpression { value });
+            }
+        }
+        BoundExpression::Binary(b)
+    }
+
+    /// Overflow only shows up here in checked mode; otherwise the fold wraps like the
+    /// evaluator would, so folding never changes what a program means.
+    fn fold_result(&mut self, result: Result<MinskValue, RuntimeError>) -> Option<MinskValue> {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) if error.kind == RuntimeErrorKind::Overflow => {
+                self.diagnostics.report_constant_overflow(error.span);
+                None
+            }
+            // anything else is left for the evaluator to report
+            Err(_) => None,
+        }
+    }
+
     fn bind_parenthesized_expression(
         &mut self,
         syntax: &ParenthesizedExpressionSyntax,
@@ -267,16 +1151,19 @@ impl Binder {
 
     fn bind_name_expression(&mut self, syntax: &NameExpressionSyntax) -> BoundExpression {
         let name = &syntax.identifier_token.text;
-        if name == "" {
+        if name.is_empty() {
             // the token was inserted by the parser.
             // an error was already reported, so return an error expression
             return BoundExpression::Literal(BoundLiteralExpression {
                 value: MinskValue::Integer(0),
             });
         }
-        let variable = self.scope.read().try_lookup(&name);
+        let variable = self.scope.read().try_lookup(name);
         if let Some(variable) = variable {
-            BoundExpression::Variable(BoundVariableExpression { variable })
+            BoundExpression::Variable(BoundVariableExpression {
+                variable,
+                span: syntax.identifier_token.span,
+            })
         } else {
             self.diagnostics
                 .report_undefined_name(syntax.identifier_token.span, name);
@@ -304,20 +1191,300 @@ impl Binder {
 
         if variable.read_only() {
             self.diagnostics
-                .report_cannot_assign(syntax.equals_token.span, &name);
+                .report_cannot_assign(syntax.assignment_token.span, &name);
         }
 
-        if bound.ty() != variable.ty() {
-            self.diagnostics.report_cannot_convert(
-                syntax.expression.span(),
-                bound.ty(),
-                variable.ty(),
+        if let Some(operator_kind) =
+            SyntaxFacts::binary_operator_of_assignment_operator(syntax.assignment_token.kind)
+        {
+            return self.bind_compound_assignment(
+                variable,
+                &syntax.assignment_token,
+                operator_kind,
+                bound,
+                syntax.span(),
             );
         }
 
+        let bound = self.bind_conversion(syntax.expression.span(), bound, variable.ty(), false);
+
         BoundExpression::Assignment(BoundAssignmentExpression {
             variable,
             expression: Box::new(bound),
         })
     }
+
+    fn bind_index_assignment_expression(
+        &mut self,
+        syntax: &IndexAssignmentExpressionSyntax,
+    ) -> BoundExpression {
+        let target = self.bind_index_expression(&syntax.target);
+        let bound = self.bind_expression(&syntax.expression);
+        let target = match target {
+            Some(target) => target,
+            None => return bound,
+        };
+
+        let operator_kind =
+            SyntaxFacts::binary_operator_of_assignment_operator(syntax.assignment_token.kind);
+        let (op, bound) = match operator_kind {
+            Some(operator_kind) => match self.bind_compound_operator(
+                target.kind(),
+                &syntax.assignment_token,
+                operator_kind,
+                bound,
+                syntax.span(),
+            ) {
+                (Some(op), bound) => (Some(op), bound),
+                (None, bound) => return bound,
+            },
+            None => {
+                let bound =
+                    self.bind_conversion(syntax.expression.span(), bound, target.kind(), false);
+                (None, bound)
+            }
+        };
+
+        BoundExpression::IndexAssignment(BoundIndexAssignmentExpression {
+            target,
+            op,
+            expression: Box::new(bound),
+            span: syntax.span(),
+            operator_span: syntax.assignment_token.span,
+        })
+    }
+
+    fn bind_member_assignment_expression(
+        &mut self,
+        syntax: &MemberAssignmentExpressionSyntax,
+    ) -> BoundExpression {
+        let target = self.bind_member_access_expression(&syntax.target);
+        let bound = self.bind_expression(&syntax.expression);
+        let target = match target {
+            Some(target) => target,
+            None => return bound,
+        };
+
+        let operator_kind =
+            SyntaxFacts::binary_operator_of_assignment_operator(syntax.assignment_token.kind);
+        let (op, bound) = match operator_kind {
+            Some(operator_kind) => match self.bind_compound_operator(
+                target.kind(),
+                &syntax.assignment_token,
+                operator_kind,
+                bound,
+                syntax.span(),
+            ) {
+                (Some(op), bound) => (Some(op), bound),
+                (None, bound) => return bound,
+            },
+            None => {
+                let bound =
+                    self.bind_conversion(syntax.expression.span(), bound, target.kind(), false);
+                (None, bound)
+            }
+        };
+
+        BoundExpression::FieldAssignment(BoundFieldAssignmentExpression {
+            target,
+            op,
+            expression: Box::new(bound),
+            span: syntax.span(),
+            operator_span: syntax.assignment_token.span,
+        })
+    }
+
+    fn bind_compound_assignment(
+        &mut self,
+        variable: VariableSymbol,
+        assignment_token: &SyntaxToken,
+        operator_kind: SyntaxKind,
+        bound: BoundExpression,
+        span: TextSpan,
+    ) -> BoundExpression {
+        let (op, bound) = match self.bind_compound_operator(
+            variable.ty(),
+            assignment_token,
+            operator_kind,
+            bound,
+            span,
+        ) {
+            (Some(op), bound) => (op, bound),
+            (None, bound) => return bound,
+        };
+
+        BoundExpression::CompoundAssignment(BoundCompoundAssignmentExpression {
+            variable,
+            op,
+            expression: Box::new(bound),
+            span,
+            operator_span: assignment_token.span,
+            returns_previous_value: false,
+        })
+    }
+
+    /// Binds the operator of `target op= bound`, along with the possibly converted `bound`.
+    fn bind_compound_operator(
+        &mut self,
+        target_type: MinskType,
+        assignment_token: &SyntaxToken,
+        operator_kind: SyntaxKind,
+        bound: BoundExpression,
+        span: TextSpan,
+    ) -> (Option<BoundBinaryOperator>, BoundExpression) {
+        let bound = if Self::classify_conversion(&bound, target_type) == Conversion::Implicit {
+            self.bind_conversion(span, bound, target_type, false)
+        } else {
+            bound
+        };
+        let op = match BoundBinaryOperator::bind(operator_kind, target_type, bound.ty()) {
+            Some(op) => op,
+            None => {
+                self.diagnostics.report_undefined_binary_operator(
+                    assignment_token.span,
+                    &assignment_token.text,
+                    target_type,
+                    bound.ty(),
+                );
+                return (None, bound);
+            }
+        };
+        if op.result_type != target_type {
+            self.diagnostics
+                .report_cannot_convert(span, op.result_type, target_type);
+        }
+        (Some(op), bound)
+    }
+
+    fn bind_increment_expression(&mut self, syntax: &IncrementExpressionSyntax) -> BoundExpression {
+        let name = &syntax.identifier_token.text;
+        let variable = match self.scope.read().try_lookup(name) {
+            Some(v) => v,
+            None => {
+                // an empty name was inserted by the parser, which already reported it
+                if !name.is_empty() {
+                    self.diagnostics
+                        .report_undefined_name(syntax.identifier_token.span, name);
+                }
+                return BoundExpression::Literal(BoundLiteralExpression {
+                    value: MinskValue::Integer(0),
+                });
+            }
+        };
+
+        if variable.read_only() {
+            self.diagnostics
+                .report_cannot_assign(syntax.operator_token.span, name);
+        }
+
+        let operator_kind =
+            SyntaxFacts::binary_operator_of_assignment_operator(syntax.operator_token.kind)
+                .unwrap();
+        let one = BoundLiteralExpression {
+            value: match variable.ty() {
+                MinskType::Float => MinskValue::Float(1.0),
+                ty => MinskValue::integral(ty, 1).unwrap_or(MinskValue::Integer(1)),
+            },
+        };
+        match BoundBinaryOperator::bind(operator_kind, variable.ty(), one.kind()) {
+            Some(op) if op.result_type == variable.ty() => {
+                BoundExpression::CompoundAssignment(BoundCompoundAssignmentExpression {
+                    variable,
+                    op,
+                    expression: Box::new(BoundExpression::Literal(one)),
+                    span: syntax.span(),
+                    operator_span: syntax.operator_token.span,
+                    returns_previous_value: syntax.is_postfix,
+                })
+            }
+            _ => {
+                self.diagnostics.report_undefined_unary_operator(
+                    syntax.operator_token.span,
+                    &syntax.operator_token.text,
+                    variable.ty(),
+                );
+                BoundExpression::Variable(BoundVariableExpression {
+                    variable,
+                    span: syntax.span(),
+                })
+            }
+        }
+    }
+
+    fn bind_call_expression(&mut self, syntax: &CallExpressionSyntax) -> BoundExpression {
+        if syntax.arguments.len() == 1 {
+            if let Some(ty) = Self::lookup_type(&syntax.identifier_token.text) {
+                let expression = self.bind_expression(&syntax.arguments[0]);
+                return self.bind_conversion(syntax.span(), expression, ty, true);
+            }
+        }
+
+        let mut arguments = syntax
+            .arguments
+            .iter()
+            .map(|a| self.bind_expression(a))
+            .collect::<Vec<_>>();
+
+        let name = &syntax.identifier_token.text;
+        let function = match self.scope.read().try_lookup_function(name) {
+            Some(f) => f,
+            None => {
+                self.diagnostics
+                    .report_undefined_function(syntax.identifier_token.span, name);
+                return BoundExpression::Literal(BoundLiteralExpression {
+                    value: MinskValue::Integer(0),
+                });
+            }
+        };
+
+        if arguments.len() != function.parameters().len() {
+            self.diagnostics.report_wrong_argument_count(
+                syntax.span(),
+                name,
+                function.parameters().len(),
+                arguments.len(),
+            );
+        } else {
+            let mut converted = Vec::<BoundExpression>::new();
+            for ((argument, argument_syntax), parameter) in arguments
+                .into_iter()
+                .zip(syntax.arguments.iter())
+                .zip(function.parameters())
+            {
+                let argument = match Self::classify_conversion(&argument, parameter.ty()) {
+                    Conversion::Identity => argument,
+                    _ if function == built_in_functions::len() => {
+                        if argument.ty().element_type().is_none() {
+                            self.diagnostics
+                                .report_expected_array(argument_syntax.span(), argument.ty());
+                        }
+                        argument
+                    }
+                    Conversion::Implicit => self.bind_conversion(
+                        argument_syntax.span(),
+                        argument,
+                        parameter.ty(),
+                        false,
+                    ),
+                    _ => {
+                        self.diagnostics.report_wrong_argument_type(
+                            argument_syntax.span(),
+                            parameter.name(),
+                            parameter.ty(),
+                            argument.ty(),
+                        );
+                        argument
+                    }
+                };
+                converted.push(argument);
+            }
+            arguments = converted;
+        }
+
+        BoundExpression::Call(BoundCallExpression {
+            function,
+            arguments,
+            span: syntax.span(),
+        })
+    }
 }
@@ -0,0 +1,15 @@
+use crate::code_analysis::minsk_type::MinskType;
+
+use super::bound_expression::BoundExpression;
+
+#[derive(Debug, Clone)]
+pub struct BoundArrayExpression {
+    pub(crate) ty: MinskType,
+    pub(crate) elements: Vec<BoundExpression>,
+}
+
+impl BoundArrayExpression {
+    pub(super) fn kind(&self) -> MinskType {
+        self.ty
+    }
+}
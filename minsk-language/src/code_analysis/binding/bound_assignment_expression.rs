@@ -2,7 +2,7 @@ use crate::code_analysis::{minsk_type::MinskType, variable_symbol::VariableSymbo
 
 use super::bound_expression::BoundExpression;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BoundAssignmentExpression {
     pub(crate) variable: VariableSymbol,
     pub(crate) expression: Box<BoundExpression>,
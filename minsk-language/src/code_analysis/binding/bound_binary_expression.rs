@@ -1,12 +1,14 @@
-use crate::code_analysis::minsk_type::MinskType;
+use crate::code_analysis::{minsk_type::MinskType, text::text_span::TextSpan};
 
 use super::{bound_binary_operator::BoundBinaryOperator, bound_expression::BoundExpression};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BoundBinaryExpression {
     pub(crate) left: Box<BoundExpression>,
     pub(crate) op: BoundBinaryOperator,
     pub(crate) right: Box<BoundExpression>,
+    pub(crate) span: TextSpan,
+    pub(crate) operator_span: TextSpan,
 }
 
 impl BoundBinaryExpression {
@@ -1,3 +1,5 @@
+use std::sync::OnceLock;
+
 use crate::code_analysis::syntax::syntax_kind::SyntaxKind;
 
 use super::{super::minsk_type::MinskType, bound_binary_operator_kind::BoundBinaryOperatorKind};
@@ -13,112 +15,151 @@ pub(crate) struct BoundBinaryOperator {
 
 impl BoundBinaryOperator {
     const fn new(syntax_kind: SyntaxKind, kind: BoundBinaryOperatorKind, ty: MinskType) -> Self {
-        Self {
-            syntax_kind,
-            kind,
-            left_type: ty,
-            right_type: ty,
-            result_type: ty,
-        }
+        Self::new_with_types(syntax_kind, kind, ty, ty, ty)
     }
     const fn new_with_result_type(
         syntax_kind: SyntaxKind,
         kind: BoundBinaryOperatorKind,
         ty: MinskType,
         result_type: MinskType,
+    ) -> Self {
+        Self::new_with_types(syntax_kind, kind, ty, ty, result_type)
+    }
+    const fn new_with_types(
+        syntax_kind: SyntaxKind,
+        kind: BoundBinaryOperatorKind,
+        left_type: MinskType,
+        right_type: MinskType,
+        result_type: MinskType,
     ) -> Self {
         Self {
             syntax_kind,
             kind,
-            left_type: ty,
-            right_type: ty,
+            left_type,
+            right_type,
             result_type,
         }
     }
-    pub(super) const fn operators() -> [BoundBinaryOperator; 14] {
-        [
-            BoundBinaryOperator::new(
-                SyntaxKind::Plus,
-                BoundBinaryOperatorKind::Addition,
-                MinskType::Integer,
-            ),
-            BoundBinaryOperator::new(
-                SyntaxKind::Minus,
-                BoundBinaryOperatorKind::Subtraction,
-                MinskType::Integer,
-            ),
-            BoundBinaryOperator::new(
-                SyntaxKind::Star,
-                BoundBinaryOperatorKind::Multiplication,
-                MinskType::Integer,
-            ),
-            BoundBinaryOperator::new(
-                SyntaxKind::Slash,
-                BoundBinaryOperatorKind::Division,
-                MinskType::Integer,
-            ),
-            BoundBinaryOperator::new_with_result_type(
-                SyntaxKind::EqualsEquals,
-                BoundBinaryOperatorKind::Equality,
-                MinskType::Integer,
-                MinskType::Boolean,
-            ),
-            BoundBinaryOperator::new_with_result_type(
-                SyntaxKind::EqualsEquals,
-                BoundBinaryOperatorKind::Equality,
-                MinskType::Boolean,
-                MinskType::Boolean,
-            ),
-            BoundBinaryOperator::new_with_result_type(
-                SyntaxKind::BangEquals,
-                BoundBinaryOperatorKind::Inequality,
-                MinskType::Integer,
-                MinskType::Boolean,
-            ),
-            BoundBinaryOperator::new_with_result_type(
-                SyntaxKind::BangEquals,
-                BoundBinaryOperatorKind::Inequality,
-                MinskType::Boolean,
-                MinskType::Boolean,
-            ),
-            BoundBinaryOperator::new_with_result_type(
-                SyntaxKind::Less,
-                BoundBinaryOperatorKind::LessThan,
-                MinskType::Integer,
-                MinskType::Boolean,
-            ),
-            BoundBinaryOperator::new_with_result_type(
-                SyntaxKind::LessEquals,
-                BoundBinaryOperatorKind::LessOrEquals,
-                MinskType::Integer,
-                MinskType::Boolean,
-            ),
-            BoundBinaryOperator::new_with_result_type(
-                SyntaxKind::Greater,
-                BoundBinaryOperatorKind::GreaterThan,
-                MinskType::Integer,
-                MinskType::Boolean,
-            ),
-            BoundBinaryOperator::new_with_result_type(
-                SyntaxKind::GreaterEquals,
-                BoundBinaryOperatorKind::GreaterOrEquals,
-                MinskType::Integer,
-                MinskType::Boolean,
-            ),
-            BoundBinaryOperator::new(
-                SyntaxKind::AmpersandAmpersand,
-                BoundBinaryOperatorKind::LogicalAnd,
-                MinskType::Boolean,
-            ),
-            BoundBinaryOperator::new(
-                SyntaxKind::PipePipe,
-                BoundBinaryOperatorKind::LogicalOr,
-                MinskType::Boolean,
-            ),
-        ]
+
+    /// Numeric operators are listed from the narrowest type to the widest, so overload
+    /// resolution can prefer the earliest of equally good candidates.
+    pub(super) fn operators() -> &'static [BoundBinaryOperator] {
+        static OPERATORS: OnceLock<Vec<BoundBinaryOperator>> = OnceLock::new();
+        OPERATORS.get_or_init(|| {
+            const ARITHMETIC: [(SyntaxKind, BoundBinaryOperatorKind); 5] = [
+                (SyntaxKind::Plus, BoundBinaryOperatorKind::Addition),
+                (SyntaxKind::Minus, BoundBinaryOperatorKind::Subtraction),
+                (SyntaxKind::Star, BoundBinaryOperatorKind::Multiplication),
+                (SyntaxKind::Slash, BoundBinaryOperatorKind::Division),
+                (SyntaxKind::Percent, BoundBinaryOperatorKind::Modulo),
+            ];
+            const BITWISE: [(SyntaxKind, BoundBinaryOperatorKind); 3] = [
+                (SyntaxKind::Ampersand, BoundBinaryOperatorKind::BitwiseAnd),
+                (SyntaxKind::Pipe, BoundBinaryOperatorKind::BitwiseOr),
+                (SyntaxKind::Hat, BoundBinaryOperatorKind::BitwiseXor),
+            ];
+            const SHIFTS: [(SyntaxKind, BoundBinaryOperatorKind); 2] = [
+                (SyntaxKind::LessLess, BoundBinaryOperatorKind::LeftShift),
+                (
+                    SyntaxKind::GreaterGreater,
+                    BoundBinaryOperatorKind::RightShift,
+                ),
+            ];
+            const EQUALITY: [(SyntaxKind, BoundBinaryOperatorKind); 2] = [
+                (SyntaxKind::EqualsEquals, BoundBinaryOperatorKind::Equality),
+                (SyntaxKind::BangEquals, BoundBinaryOperatorKind::Inequality),
+            ];
+            const RELATIONAL: [(SyntaxKind, BoundBinaryOperatorKind); 4] = [
+                (SyntaxKind::Less, BoundBinaryOperatorKind::LessThan),
+                (
+                    SyntaxKind::LessEquals,
+                    BoundBinaryOperatorKind::LessOrEquals,
+                ),
+                (SyntaxKind::Greater, BoundBinaryOperatorKind::GreaterThan),
+                (
+                    SyntaxKind::GreaterEquals,
+                    BoundBinaryOperatorKind::GreaterOrEquals,
+                ),
+            ];
+
+            let mut operators = vec![
+                BoundBinaryOperator::new(
+                    SyntaxKind::Plus,
+                    BoundBinaryOperatorKind::Addition,
+                    MinskType::String,
+                ),
+                BoundBinaryOperator::new(
+                    SyntaxKind::AmpersandAmpersand,
+                    BoundBinaryOperatorKind::LogicalAnd,
+                    MinskType::Boolean,
+                ),
+                BoundBinaryOperator::new(
+                    SyntaxKind::PipePipe,
+                    BoundBinaryOperatorKind::LogicalOr,
+                    MinskType::Boolean,
+                ),
+            ];
+            for &(syntax_kind, kind) in BITWISE.iter() {
+                operators.push(BoundBinaryOperator::new(
+                    syntax_kind,
+                    kind,
+                    MinskType::Boolean,
+                ));
+            }
+            for &ty in [MinskType::Boolean, MinskType::String].iter() {
+                for &(syntax_kind, kind) in EQUALITY.iter() {
+                    operators.push(BoundBinaryOperator::new_with_result_type(
+                        syntax_kind,
+                        kind,
+                        ty,
+                        MinskType::Boolean,
+                    ));
+                }
+            }
+            for &(syntax_kind, kind) in EQUALITY.iter().chain(RELATIONAL.iter()) {
+                operators.push(BoundBinaryOperator::new_with_result_type(
+                    syntax_kind,
+                    kind,
+                    MinskType::Char,
+                    MinskType::Boolean,
+                ));
+            }
+            let numeric = MinskType::INTEGRAL
+                .iter()
+                .chain(std::iter::once(&MinskType::Float));
+            for &ty in numeric {
+                for &(syntax_kind, kind) in ARITHMETIC.iter() {
+                    operators.push(BoundBinaryOperator::new(syntax_kind, kind, ty));
+                }
+                if ty.is_integral() {
+                    for &(syntax_kind, kind) in BITWISE.iter() {
+                        operators.push(BoundBinaryOperator::new(syntax_kind, kind, ty));
+                    }
+                    // the shift count is always an int, whatever is being shifted
+                    for &(syntax_kind, kind) in SHIFTS.iter() {
+                        operators.push(BoundBinaryOperator::new_with_types(
+                            syntax_kind,
+                            kind,
+                            ty,
+                            MinskType::Integer,
+                            ty,
+                        ));
+                    }
+                }
+                for &(syntax_kind, kind) in EQUALITY.iter().chain(RELATIONAL.iter()) {
+                    operators.push(BoundBinaryOperator::new_with_result_type(
+                        syntax_kind,
+                        kind,
+                        ty,
+                        MinskType::Boolean,
+                    ));
+                }
+            }
+            operators
+        })
     }
 
-    pub(super) fn bind(
+    pub(crate) fn bind(
         syntax_kind: SyntaxKind,
         left_type: MinskType,
         right_type: MinskType,
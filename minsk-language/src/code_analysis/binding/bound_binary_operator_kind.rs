@@ -4,7 +4,15 @@ pub(crate) enum BoundBinaryOperatorKind {
     Subtraction,
     Multiplication,
     Division,
+    Modulo,
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+    LeftShift,
+    RightShift,
+    /// Short-circuits: the right operand is only evaluated if the left is true.
     LogicalAnd,
+    /// Short-circuits: the right operand is only evaluated if the left is false.
     LogicalOr,
     Equality,
     Inequality,
@@ -1,6 +1,6 @@
 use super::bound_statement::BoundStatement;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BoundBlockStatement {
     statements: Vec<BoundStatement>,
 }
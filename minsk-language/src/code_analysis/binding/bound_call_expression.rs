@@ -0,0 +1,18 @@
+use crate::code_analysis::{
+    function_symbol::FunctionSymbol, minsk_type::MinskType, text::text_span::TextSpan,
+};
+
+use super::bound_expression::BoundExpression;
+
+#[derive(Debug, Clone)]
+pub struct BoundCallExpression {
+    pub(crate) function: FunctionSymbol,
+    pub(crate) arguments: Vec<BoundExpression>,
+    pub(crate) span: TextSpan,
+}
+
+impl BoundCallExpression {
+    pub(super) fn kind(&self) -> MinskType {
+        self.function.ty()
+    }
+}
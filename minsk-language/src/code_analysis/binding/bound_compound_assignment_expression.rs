@@ -0,0 +1,23 @@
+use crate::code_analysis::{
+    minsk_type::MinskType, text::text_span::TextSpan, variable_symbol::VariableSymbol,
+};
+
+use super::{bound_binary_operator::BoundBinaryOperator, bound_expression::BoundExpression};
+
+/// `x += e` and friends, as well as `++x` and `x++` (which add a literal 1).
+#[derive(Debug, Clone)]
+pub struct BoundCompoundAssignmentExpression {
+    pub(crate) variable: VariableSymbol,
+    pub(crate) op: BoundBinaryOperator,
+    pub(crate) expression: Box<BoundExpression>,
+    pub(crate) span: TextSpan,
+    pub(crate) operator_span: TextSpan,
+    /// Set for postfix increments, which evaluate to the value before the assignment.
+    pub(crate) returns_previous_value: bool,
+}
+
+impl BoundCompoundAssignmentExpression {
+    pub(super) fn kind(&self) -> MinskType {
+        self.variable.ty()
+    }
+}
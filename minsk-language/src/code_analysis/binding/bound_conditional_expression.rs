@@ -0,0 +1,17 @@
+use crate::code_analysis::minsk_type::MinskType;
+
+use super::bound_expression::BoundExpression;
+
+/// `condition ? when_true : when_false`, where both branches have the same type.
+#[derive(Debug, Clone)]
+pub struct BoundConditionalExpression {
+    pub(crate) condition: Box<BoundExpression>,
+    pub(crate) when_true: Box<BoundExpression>,
+    pub(crate) when_false: Box<BoundExpression>,
+}
+
+impl BoundConditionalExpression {
+    pub(super) fn kind(&self) -> MinskType {
+        self.when_true.ty()
+    }
+}
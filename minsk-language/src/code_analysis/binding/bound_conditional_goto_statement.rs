@@ -0,0 +1,30 @@
+use super::{bound_expression::BoundExpression, bound_label::BoundLabel};
+
+#[derive(Debug, Clone)]
+pub struct BoundConditionalGotoStatement {
+    label: BoundLabel,
+    condition: BoundExpression,
+    jump_if_true: bool,
+}
+
+impl BoundConditionalGotoStatement {
+    pub(crate) fn new(label: BoundLabel, condition: BoundExpression, jump_if_true: bool) -> Self {
+        Self {
+            label,
+            condition,
+            jump_if_true,
+        }
+    }
+
+    pub(crate) fn label(&self) -> &BoundLabel {
+        &self.label
+    }
+
+    pub(crate) fn condition(&self) -> &BoundExpression {
+        &self.condition
+    }
+
+    pub(crate) fn jump_if_true(&self) -> bool {
+        self.jump_if_true
+    }
+}
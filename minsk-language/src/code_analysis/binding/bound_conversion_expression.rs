@@ -0,0 +1,16 @@
+use crate::code_analysis::{minsk_type::MinskType, text::text_span::TextSpan};
+
+use super::bound_expression::BoundExpression;
+
+#[derive(Debug, Clone)]
+pub struct BoundConversionExpression {
+    pub(crate) ty: MinskType,
+    pub(crate) expression: Box<BoundExpression>,
+    pub(crate) span: TextSpan,
+}
+
+impl BoundConversionExpression {
+    pub(super) fn kind(&self) -> MinskType {
+        self.ty
+    }
+}
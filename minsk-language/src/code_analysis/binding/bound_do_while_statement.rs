@@ -0,0 +1,43 @@
+use super::{
+    bound_expression::BoundExpression, bound_label::BoundLabel, bound_statement::BoundStatement,
+};
+
+#[derive(Debug, Clone)]
+pub struct BoundDoWhileStatement {
+    body: Box<BoundStatement>,
+    condition: BoundExpression,
+    break_label: BoundLabel,
+    continue_label: BoundLabel,
+}
+
+impl BoundDoWhileStatement {
+    pub(crate) fn new(
+        body: Box<BoundStatement>,
+        condition: BoundExpression,
+        break_label: BoundLabel,
+        continue_label: BoundLabel,
+    ) -> Self {
+        Self {
+            body,
+            condition,
+            break_label,
+            continue_label,
+        }
+    }
+
+    pub(crate) fn body(&self) -> &BoundStatement {
+        &self.body
+    }
+
+    pub(crate) fn condition(&self) -> &BoundExpression {
+        &self.condition
+    }
+
+    pub(crate) fn break_label(&self) -> &BoundLabel {
+        &self.break_label
+    }
+
+    pub(crate) fn continue_label(&self) -> &BoundLabel {
+        &self.continue_label
+    }
+}
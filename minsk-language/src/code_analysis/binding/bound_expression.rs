@@ -6,17 +6,40 @@ use super::{
 };
 
 use super::{
-    bound_binary_expression::BoundBinaryExpression,
+    bound_array_expression::BoundArrayExpression,
+    bound_field_assignment_expression::BoundFieldAssignmentExpression,
+    bound_field_expression::BoundFieldExpression,
+    bound_index_assignment_expression::BoundIndexAssignmentExpression,
+    bound_index_expression::BoundIndexExpression, bound_step_expression::BoundStepExpression,
+    bound_struct_expression::BoundStructExpression,
+};
+
+use super::{
+    bound_binary_expression::BoundBinaryExpression, bound_call_expression::BoundCallExpression,
+    bound_compound_assignment_expression::BoundCompoundAssignmentExpression,
+    bound_conditional_expression::BoundConditionalExpression,
+    bound_conversion_expression::BoundConversionExpression,
     bound_literal_expression::BoundLiteralExpression, bound_unary_expression::BoundUnaryExpression,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum BoundExpression {
     Binary(BoundBinaryExpression),
     Literal(BoundLiteralExpression),
     Unary(BoundUnaryExpression),
     Variable(BoundVariableExpression),
     Assignment(BoundAssignmentExpression),
+    Call(BoundCallExpression),
+    CompoundAssignment(BoundCompoundAssignmentExpression),
+    Conversion(BoundConversionExpression),
+    Array(BoundArrayExpression),
+    Index(BoundIndexExpression),
+    IndexAssignment(BoundIndexAssignmentExpression),
+    Struct(BoundStructExpression),
+    Field(BoundFieldExpression),
+    FieldAssignment(BoundFieldAssignmentExpression),
+    Conditional(BoundConditionalExpression),
+    Step(BoundStepExpression),
 }
 
 impl BoundExpression {
@@ -27,6 +50,17 @@ impl BoundExpression {
             BoundExpression::Unary(u) => u.kind(),
             BoundExpression::Variable(v) => v.kind(),
             BoundExpression::Assignment(a) => a.kind(),
+            BoundExpression::Call(c) => c.kind(),
+            BoundExpression::CompoundAssignment(c) => c.kind(),
+            BoundExpression::Conversion(c) => c.kind(),
+            BoundExpression::Array(a) => a.kind(),
+            BoundExpression::Index(i) => i.kind(),
+            BoundExpression::IndexAssignment(a) => a.kind(),
+            BoundExpression::Struct(s) => s.kind(),
+            BoundExpression::Field(f) => f.kind(),
+            BoundExpression::FieldAssignment(a) => a.kind(),
+            BoundExpression::Conditional(c) => c.kind(),
+            BoundExpression::Step(s) => s.kind(),
         }
     }
 }
@@ -1,6 +1,6 @@
 use super::bound_expression::BoundExpression;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BoundExpressionStatement {
     expression: BoundExpression,
 }
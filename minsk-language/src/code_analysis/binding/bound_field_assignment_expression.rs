@@ -0,0 +1,22 @@
+use crate::code_analysis::{minsk_type::MinskType, text::text_span::TextSpan};
+
+use super::{
+    bound_binary_operator::BoundBinaryOperator, bound_expression::BoundExpression,
+    bound_field_expression::BoundFieldExpression,
+};
+
+/// `s.x = e`, or `s.x += e` and friends when `op` is set.
+#[derive(Debug, Clone)]
+pub struct BoundFieldAssignmentExpression {
+    pub(crate) target: BoundFieldExpression,
+    pub(crate) op: Option<BoundBinaryOperator>,
+    pub(crate) expression: Box<BoundExpression>,
+    pub(crate) span: TextSpan,
+    pub(crate) operator_span: TextSpan,
+}
+
+impl BoundFieldAssignmentExpression {
+    pub(super) fn kind(&self) -> MinskType {
+        self.target.kind()
+    }
+}
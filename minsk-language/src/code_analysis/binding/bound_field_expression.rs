@@ -0,0 +1,17 @@
+use crate::code_analysis::minsk_type::MinskType;
+
+use super::bound_expression::BoundExpression;
+
+#[derive(Debug, Clone)]
+pub struct BoundFieldExpression {
+    pub(crate) expression: Box<BoundExpression>,
+    /// The position of the field in its struct's declaration.
+    pub(crate) index: usize,
+    pub(crate) ty: MinskType,
+}
+
+impl BoundFieldExpression {
+    pub(super) fn kind(&self) -> MinskType {
+        self.ty
+    }
+}
@@ -1,27 +1,43 @@
-use crate::code_analysis::variable_symbol::VariableSymbol;
+use crate::code_analysis::{text::text_span::TextSpan, variable_symbol::VariableSymbol};
 
-use super::{bound_expression::BoundExpression, bound_statement::BoundStatement};
+use super::{
+    bound_expression::BoundExpression, bound_label::BoundLabel, bound_statement::BoundStatement,
+};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BoundForStatement {
     variable: VariableSymbol,
     lower_bound: BoundExpression,
     upper_bound: BoundExpression,
+    step: Option<BoundExpression>,
     body: Box<BoundStatement>,
+    break_label: BoundLabel,
+    continue_label: BoundLabel,
+    /// The loop's header, from `for` to the end of the upper bound or step.
+    span: TextSpan,
 }
 
 impl BoundForStatement {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         variable: VariableSymbol,
         lower_bound: BoundExpression,
         upper_bound: BoundExpression,
+        step: Option<BoundExpression>,
         body: Box<BoundStatement>,
+        break_label: BoundLabel,
+        continue_label: BoundLabel,
+        span: TextSpan,
     ) -> Self {
         Self {
             variable,
             lower_bound,
             upper_bound,
+            step,
             body,
+            break_label,
+            continue_label,
+            span,
         }
     }
 
@@ -37,7 +53,24 @@ impl BoundForStatement {
         &self.upper_bound
     }
 
+    /// `None` when the loop counts up by one.
+    pub(crate) fn step(&self) -> Option<&BoundExpression> {
+        self.step.as_ref()
+    }
+
     pub(crate) fn body(&self) -> &BoundStatement {
         &self.body
     }
+
+    pub(crate) fn break_label(&self) -> &BoundLabel {
+        &self.break_label
+    }
+
+    pub(crate) fn continue_label(&self) -> &BoundLabel {
+        &self.continue_label
+    }
+
+    pub(crate) fn span(&self) -> TextSpan {
+        self.span
+    }
 }
@@ -1,13 +1,18 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
-use crate::code_analysis::{diagnostic::Diagnostic, variable_symbol::VariableSymbol};
+use crate::code_analysis::{
+    diagnostic::Diagnostic, function_symbol::FunctionSymbol, minsk_type::MinskType,
+    variable_symbol::VariableSymbol,
+};
 
-use super::bound_statement::BoundStatement;
+use super::{bound_block_statement::BoundBlockStatement, bound_statement::BoundStatement};
 
 pub(crate) struct BoundGlobalScope {
     previous: Option<Arc<BoundGlobalScope>>,
     diagnostics: Vec<Diagnostic>,
     variables: Vec<VariableSymbol>,
+    functions: HashMap<FunctionSymbol, BoundBlockStatement>,
+    types: Vec<(String, MinskType)>,
     statement: BoundStatement,
 }
 
@@ -16,12 +21,16 @@ impl BoundGlobalScope {
         previous: Option<Arc<BoundGlobalScope>>,
         diagnostics: Vec<Diagnostic>,
         variables: Vec<VariableSymbol>,
+        functions: HashMap<FunctionSymbol, BoundBlockStatement>,
+        types: Vec<(String, MinskType)>,
         statement: BoundStatement,
     ) -> Self {
         Self {
             previous,
             diagnostics,
             variables,
+            functions,
+            types,
             statement,
         }
     }
@@ -38,6 +47,22 @@ impl BoundGlobalScope {
         self.variables.iter().cloned()
     }
 
+    pub(crate) fn functions(&self) -> impl Iterator<Item = FunctionSymbol> + '_ {
+        self.functions.keys().cloned()
+    }
+
+    /// The struct types declared by this submission.
+    pub(crate) fn types(&self) -> impl Iterator<Item = (&str, MinskType)> + '_ {
+        self.types.iter().map(|(name, ty)| (name.as_str(), *ty))
+    }
+
+    /// The bodies of the functions declared by this submission.
+    pub(crate) fn function_bodies(
+        &self,
+    ) -> impl Iterator<Item = (&FunctionSymbol, &BoundBlockStatement)> + '_ {
+        self.functions.iter()
+    }
+
     pub(crate) fn statement(&self) -> &BoundStatement {
         &self.statement
     }
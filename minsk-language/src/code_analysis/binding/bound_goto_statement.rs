@@ -0,0 +1,16 @@
+use super::bound_label::BoundLabel;
+
+#[derive(Debug, Clone)]
+pub struct BoundGotoStatement {
+    label: BoundLabel,
+}
+
+impl BoundGotoStatement {
+    pub(crate) fn new(label: BoundLabel) -> Self {
+        Self { label }
+    }
+
+    pub(crate) fn label(&self) -> &BoundLabel {
+        &self.label
+    }
+}
@@ -1,6 +1,6 @@
 use super::{bound_expression::BoundExpression, bound_statement::BoundStatement};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BoundIfStatement {
     condition: BoundExpression,
     then_statement: Box<BoundStatement>,
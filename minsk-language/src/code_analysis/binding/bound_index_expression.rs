@@ -0,0 +1,17 @@
+use crate::code_analysis::{minsk_type::MinskType, text::text_span::TextSpan};
+
+use super::bound_expression::BoundExpression;
+
+#[derive(Debug, Clone)]
+pub struct BoundIndexExpression {
+    pub(crate) array: Box<BoundExpression>,
+    pub(crate) index: Box<BoundExpression>,
+    /// Where an out-of-bounds index is reported.
+    pub(crate) index_span: TextSpan,
+}
+
+impl BoundIndexExpression {
+    pub(super) fn kind(&self) -> MinskType {
+        self.array.ty().element_type().unwrap()
+    }
+}
@@ -0,0 +1,18 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BoundLabel {
+    name: String,
+}
+
+impl BoundLabel {
+    pub(crate) fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+impl Display for BoundLabel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
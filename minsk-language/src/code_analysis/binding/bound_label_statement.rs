@@ -0,0 +1,16 @@
+use super::bound_label::BoundLabel;
+
+#[derive(Debug, Clone)]
+pub struct BoundLabelStatement {
+    label: BoundLabel,
+}
+
+impl BoundLabelStatement {
+    pub(crate) fn new(label: BoundLabel) -> Self {
+        Self { label }
+    }
+
+    pub(crate) fn label(&self) -> &BoundLabel {
+        &self.label
+    }
+}
@@ -0,0 +1,16 @@
+use super::bound_expression::BoundExpression;
+
+#[derive(Debug, Clone)]
+pub struct BoundReturnStatement {
+    expression: Option<BoundExpression>,
+}
+
+impl BoundReturnStatement {
+    pub(crate) fn new(expression: Option<BoundExpression>) -> Self {
+        Self { expression }
+    }
+
+    pub(crate) fn expression(&self) -> Option<&BoundExpression> {
+        self.expression.as_ref()
+    }
+}
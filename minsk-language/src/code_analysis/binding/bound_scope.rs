@@ -2,10 +2,14 @@ use std::{collections::HashMap, sync::Arc};
 
 use parking_lot::RwLock;
 
-use crate::code_analysis::variable_symbol::VariableSymbol;
+use crate::code_analysis::{
+    function_symbol::FunctionSymbol, minsk_type::MinskType, variable_symbol::VariableSymbol,
+};
 
 pub(crate) struct BoundScope {
     variables: HashMap<String, VariableSymbol>,
+    functions: HashMap<String, FunctionSymbol>,
+    types: HashMap<String, MinskType>,
     parent: Option<Arc<RwLock<BoundScope>>>,
 }
 
@@ -13,6 +17,8 @@ impl BoundScope {
     pub(super) fn new(parent: Option<Arc<RwLock<BoundScope>>>) -> Self {
         Self {
             variables: HashMap::new(),
+            functions: HashMap::new(),
+            types: HashMap::new(),
             parent,
         }
     }
@@ -40,4 +46,42 @@ impl BoundScope {
     pub(super) fn declared_variables(&self) -> impl Iterator<Item = &VariableSymbol> {
         self.variables.values()
     }
+
+    pub(super) fn try_declare_function(&mut self, function: FunctionSymbol) -> bool {
+        if self.functions.contains_key(function.name()) {
+            return false;
+        }
+
+        self.functions.insert(function.name().to_string(), function);
+        true
+    }
+
+    pub(super) fn try_lookup_function(&self, name: &str) -> Option<FunctionSymbol> {
+        self.functions.get(name).cloned().or_else(|| {
+            self.parent
+                .as_ref()
+                .and_then(|p| p.read().try_lookup_function(name))
+        })
+    }
+
+    pub(super) fn try_declare_type(&mut self, name: &str, ty: MinskType) -> bool {
+        if self.types.contains_key(name) {
+            return false;
+        }
+
+        self.types.insert(name.to_string(), ty);
+        true
+    }
+
+    pub(super) fn try_lookup_type(&self, name: &str) -> Option<MinskType> {
+        self.types.get(name).copied().or_else(|| {
+            self.parent
+                .as_ref()
+                .and_then(|p| p.read().try_lookup_type(name))
+        })
+    }
+
+    pub(super) fn declared_types(&self) -> impl Iterator<Item = (&String, &MinskType)> {
+        self.types.iter()
+    }
 }
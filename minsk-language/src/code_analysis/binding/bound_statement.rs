@@ -1,16 +1,25 @@
 use super::{
     bound_block_statement::BoundBlockStatement,
+    bound_conditional_goto_statement::BoundConditionalGotoStatement,
+    bound_do_while_statement::BoundDoWhileStatement,
     bound_expression_statement::BoundExpressionStatement, bound_for_statement::BoundForStatement,
-    bound_if_statement::BoundIfStatement, bound_variable_declaration::BoundVariableDeclaration,
+    bound_goto_statement::BoundGotoStatement, bound_if_statement::BoundIfStatement,
+    bound_label_statement::BoundLabelStatement, bound_return_statement::BoundReturnStatement,
+    bound_variable_declaration::BoundVariableDeclaration,
     bound_while_statement::BoundWhileStatement,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum BoundStatement {
     Block(BoundBlockStatement),
+    ConditionalGoto(BoundConditionalGotoStatement),
+    DoWhile(BoundDoWhileStatement),
     Expression(BoundExpressionStatement),
-    For(BoundForStatement),
+    For(Box<BoundForStatement>),
+    Goto(BoundGotoStatement),
     If(BoundIfStatement),
+    Label(BoundLabelStatement),
+    Return(BoundReturnStatement),
     VariableDeclaration(BoundVariableDeclaration),
     While(BoundWhileStatement),
 }
@@ -0,0 +1,16 @@
+use crate::code_analysis::{minsk_type::MinskType, text::text_span::TextSpan};
+
+use super::bound_expression::BoundExpression;
+
+/// The step of a `for` loop that isn't known at compile time, checked for zero when it's evaluated.
+#[derive(Debug, Clone)]
+pub struct BoundStepExpression {
+    pub(crate) expression: Box<BoundExpression>,
+    pub(crate) span: TextSpan,
+}
+
+impl BoundStepExpression {
+    pub(super) fn kind(&self) -> MinskType {
+        self.expression.ty()
+    }
+}
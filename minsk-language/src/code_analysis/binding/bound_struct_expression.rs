@@ -0,0 +1,16 @@
+use crate::code_analysis::minsk_type::MinskType;
+
+use super::bound_expression::BoundExpression;
+
+#[derive(Debug, Clone)]
+pub struct BoundStructExpression {
+    pub(crate) ty: MinskType,
+    /// The field initializers, in the order the fields were declared.
+    pub(crate) fields: Vec<BoundExpression>,
+}
+
+impl BoundStructExpression {
+    pub(super) fn kind(&self) -> MinskType {
+        self.ty
+    }
+}
@@ -1,11 +1,12 @@
-use super::super::minsk_type::MinskType;
+use super::super::{minsk_type::MinskType, text::text_span::TextSpan};
 
 use super::{bound_expression::BoundExpression, bound_unary_operator::BoundUnaryOperator};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BoundUnaryExpression {
     pub(crate) op: BoundUnaryOperator,
     pub(crate) operand: Box<BoundExpression>,
+    pub(crate) operator_span: TextSpan,
 }
 
 impl BoundUnaryExpression {
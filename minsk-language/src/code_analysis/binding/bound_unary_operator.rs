@@ -1,3 +1,5 @@
+use std::sync::OnceLock;
+
 use crate::code_analysis::syntax::syntax_kind::SyntaxKind;
 
 use super::super::minsk_type::MinskType;
@@ -23,35 +25,41 @@ impl BoundUnaryOperator {
 }
 
 impl BoundUnaryOperator {
-    pub(super) const fn operators() -> [BoundUnaryOperator; 3] {
-        [
-            BoundUnaryOperator::new(
+    /// Numeric operators are listed from the narrowest type to the widest, like
+    /// [`BoundBinaryOperator::operators`](super::bound_binary_operator::BoundBinaryOperator::operators).
+    pub(super) fn operators() -> &'static [BoundUnaryOperator] {
+        static OPERATORS: OnceLock<Vec<BoundUnaryOperator>> = OnceLock::new();
+        OPERATORS.get_or_init(|| {
+            let mut operators = vec![BoundUnaryOperator::new(
                 SyntaxKind::Bang,
                 BoundUnaryOperatorKind::LogicalNegation,
                 MinskType::Boolean,
-            ),
-            BoundUnaryOperator::new(
-                SyntaxKind::Plus,
-                BoundUnaryOperatorKind::Identity,
-                MinskType::Integer,
-            ),
-            BoundUnaryOperator::new(
-                SyntaxKind::Minus,
-                BoundUnaryOperatorKind::Negation,
-                MinskType::Integer,
-            ),
-        ]
-    }
-
-    pub(super) fn bind(
-        syntax_kind: SyntaxKind,
-        operand_type: MinskType,
-    ) -> Option<BoundUnaryOperator> {
-        for op in Self::operators().iter() {
-            if op.syntax_kind == syntax_kind && op.operand_type == operand_type {
-                return Some(*op);
+            )];
+            let numeric = MinskType::INTEGRAL
+                .iter()
+                .chain(std::iter::once(&MinskType::Float));
+            for &ty in numeric {
+                operators.push(BoundUnaryOperator::new(
+                    SyntaxKind::Plus,
+                    BoundUnaryOperatorKind::Identity,
+                    ty,
+                ));
+                if ty != MinskType::UInt {
+                    operators.push(BoundUnaryOperator::new(
+                        SyntaxKind::Minus,
+                        BoundUnaryOperatorKind::Negation,
+                        ty,
+                    ));
+                }
+                if ty.is_integral() {
+                    operators.push(BoundUnaryOperator::new(
+                        SyntaxKind::Tilde,
+                        BoundUnaryOperatorKind::OnesComplement,
+                        ty,
+                    ));
+                }
             }
-        }
-        None
+            operators
+        })
     }
 }
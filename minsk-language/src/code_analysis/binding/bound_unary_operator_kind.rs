@@ -3,4 +3,5 @@ pub(crate) enum BoundUnaryOperatorKind {
     Identity,
     Negation,
     LogicalNegation,
+    OnesComplement,
 }
@@ -2,14 +2,14 @@ use crate::code_analysis::variable_symbol::VariableSymbol;
 
 use super::bound_expression::BoundExpression;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BoundVariableDeclaration {
     variable: VariableSymbol,
     initializer: BoundExpression,
 }
 
 impl BoundVariableDeclaration {
-    pub(super) fn new(variable: VariableSymbol, initializer: BoundExpression) -> Self {
+    pub(crate) fn new(variable: VariableSymbol, initializer: BoundExpression) -> Self {
         Self {
             variable,
             initializer,
@@ -1,8 +1,11 @@
-use crate::code_analysis::{minsk_type::MinskType, variable_symbol::VariableSymbol};
+use crate::code_analysis::{
+    minsk_type::MinskType, text::text_span::TextSpan, variable_symbol::VariableSymbol,
+};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BoundVariableExpression {
     pub(crate) variable: VariableSymbol,
+    pub(crate) span: TextSpan,
 }
 
 impl BoundVariableExpression {
@@ -1,14 +1,28 @@
-use super::{bound_expression::BoundExpression, bound_statement::BoundStatement};
+use super::{
+    bound_expression::BoundExpression, bound_label::BoundLabel, bound_statement::BoundStatement,
+};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BoundWhileStatement {
     condition: BoundExpression,
     body: Box<BoundStatement>,
+    break_label: BoundLabel,
+    continue_label: BoundLabel,
 }
 
 impl BoundWhileStatement {
-    pub(crate) fn new(condition: BoundExpression, body: Box<BoundStatement>) -> Self {
-        Self { condition, body }
+    pub(crate) fn new(
+        condition: BoundExpression,
+        body: Box<BoundStatement>,
+        break_label: BoundLabel,
+        continue_label: BoundLabel,
+    ) -> Self {
+        Self {
+            condition,
+            body,
+            break_label,
+            continue_label,
+        }
     }
 
     pub(crate) fn condition(&self) -> &BoundExpression {
@@ -18,4 +32,12 @@ impl BoundWhileStatement {
     pub(crate) fn body(&self) -> &BoundStatement {
         &self.body
     }
+
+    pub(crate) fn break_label(&self) -> &BoundLabel {
+        &self.break_label
+    }
+
+    pub(crate) fn continue_label(&self) -> &BoundLabel {
+        &self.continue_label
+    }
 }
@@ -0,0 +1,51 @@
+use super::super::minsk_type::MinskType;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Conversion {
+    None,
+    Identity,
+    /// Applied wherever a value of the target type is expected.
+    Implicit,
+    /// Only applied when written out, e.g. `int(x)`.
+    Explicit,
+}
+
+impl Conversion {
+    pub(crate) fn classify(from: MinskType, to: MinskType) -> Self {
+        match (from, to) {
+            _ if from == to => Conversion::Identity,
+            _ if Self::widens(from, to) => Conversion::Implicit,
+            _ if from.is_integral() && to.is_integral() => Conversion::Explicit,
+            (MinskType::Float, t) | (MinskType::Boolean, t) | (t, MinskType::Boolean)
+                if t.is_integral() =>
+            {
+                Conversion::Explicit
+            }
+            (MinskType::Char, t) | (t, MinskType::Char) if t.is_integral() => Conversion::Explicit,
+            (MinskType::Float | MinskType::Boolean | MinskType::Char, MinskType::String)
+            | (MinskType::String, MinskType::Float | MinskType::Boolean | MinskType::Char) => {
+                Conversion::Explicit
+            }
+            (t, MinskType::String) | (MinskType::String, t) if t.is_integral() => {
+                Conversion::Explicit
+            }
+            _ => Conversion::None,
+        }
+    }
+
+    /// Whether every value of `from` is representable as a `to`.
+    fn widens(from: MinskType, to: MinskType) -> bool {
+        matches!(
+            (from, to),
+            (
+                MinskType::Int8,
+                MinskType::Int16 | MinskType::Integer | MinskType::Int64
+            ) | (MinskType::Int16, MinskType::Integer | MinskType::Int64)
+                | (MinskType::Integer | MinskType::UInt, MinskType::Int64)
+        ) || (from.is_integral() && to == MinskType::Float)
+    }
+
+    pub(crate) fn exists(self) -> bool {
+        self != Conversion::None
+    }
+}
@@ -0,0 +1,39 @@
+use super::{
+    function_symbol::FunctionSymbol, minsk_type::MinskType, parameter_symbol::ParameterSymbol,
+};
+
+pub(crate) fn print() -> FunctionSymbol {
+    FunctionSymbol::new(
+        "print".to_string(),
+        vec![ParameterSymbol::new("text".to_string(), MinskType::String)],
+        MinskType::Null,
+    )
+}
+
+pub(crate) fn input() -> FunctionSymbol {
+    FunctionSymbol::new("input".to_string(), vec![], MinskType::String)
+}
+
+pub(crate) fn rnd() -> FunctionSymbol {
+    FunctionSymbol::new(
+        "rnd".to_string(),
+        vec![ParameterSymbol::new("max".to_string(), MinskType::Integer)],
+        MinskType::Integer,
+    )
+}
+
+/// The parameter stands in for an array of any type, which the binder special-cases.
+pub(crate) fn len() -> FunctionSymbol {
+    FunctionSymbol::new(
+        "len".to_string(),
+        vec![ParameterSymbol::new(
+            "array".to_string(),
+            MinskType::array(MinskType::Null),
+        )],
+        MinskType::Integer,
+    )
+}
+
+pub(crate) fn all() -> Vec<FunctionSymbol> {
+    vec![print(), input(), rnd(), len()]
+}
@@ -1,57 +1,136 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, panic, sync::Arc, thread};
 
 use super::{
-    binding::{binder::Binder, bound_global_scope::BoundGlobalScope},
-    evaluation_result::EvaluationResult,
+    binding::{
+        binder::Binder, bound_block_statement::BoundBlockStatement,
+        bound_global_scope::BoundGlobalScope, bound_statement::BoundStatement,
+    },
+    compilation_options::CompilationOptions,
+    evaluation_result::{EvaluationError, EvaluationResult},
     evaluator::Evaluator,
+    function_symbol::FunctionSymbol,
+    host::Host,
+    lowering::lowerer::Lowerer,
     minsk_value::MinskValue,
     syntax::syntax_tree::SyntaxTree,
     variable_symbol::VariableSymbol,
 };
 
+const EVALUATOR_STACK_SIZE: usize = 64 * 1024 * 1024;
+
 #[derive(Clone)]
 pub struct Compilation {
+    options: CompilationOptions,
     syntax_tree: SyntaxTree,
     previous: Option<Box<Compilation>>,
     global_scope: Option<Arc<BoundGlobalScope>>,
+    /// This submission's function bodies, lowered once when it is bound.
+    function_bodies: Arc<HashMap<FunctionSymbol, BoundBlockStatement>>,
 }
 
 impl Compilation {
-    fn new_internal(previous: Option<Compilation>, syntax_tree: SyntaxTree) -> Self {
+    fn new_internal(
+        options: CompilationOptions,
+        previous: Option<Compilation>,
+        syntax_tree: SyntaxTree,
+    ) -> Self {
         Self {
+            options,
             syntax_tree,
             previous: previous.map(Box::new),
             global_scope: None,
+            function_bodies: Arc::default(),
         }
     }
     pub fn new(syntax_tree: SyntaxTree) -> Self {
-        Self::new_internal(None, syntax_tree)
+        Self::with_options(syntax_tree, CompilationOptions::default())
+    }
+    pub fn with_options(syntax_tree: SyntaxTree, options: CompilationOptions) -> Self {
+        Self::new_internal(options, None, syntax_tree)
     }
     pub fn evaluate(
         &mut self,
         variables: &mut HashMap<VariableSymbol, MinskValue>,
+        host: &mut dyn Host,
     ) -> EvaluationResult {
         let mut diagnostics = self.syntax_tree.diagnostics().collect::<Vec<_>>();
         diagnostics.append(&mut self.global_scope().diagnostics().collect::<Vec<_>>());
         if !diagnostics.is_empty() {
-            return Err(diagnostics);
+            return Err(EvaluationError::Diagnostics(diagnostics));
         }
-        let value = Evaluator::new(variables).evaluate(self.global_scope().statement());
-        Ok(value)
+        let global_scope = self.global_scope();
+        let statement = Lowerer::lower(global_scope.statement());
+        let functions = self.function_bodies();
+        let submission_functions = global_scope.functions().collect();
+        let options = self.options;
+        // the evaluator recurses on the native stack, so give it a known amount of room
+        // rather than whatever the calling thread happens to have
+        thread::scope(|scope| {
+            thread::Builder::new()
+                .stack_size(EVALUATOR_STACK_SIZE)
+                .spawn_scoped(scope, || {
+                    Evaluator::new(functions, submission_functions, variables, host, options)
+                        .evaluate(&statement)
+                })
+                .unwrap()
+                .join()
+                .unwrap_or_else(|panic| panic::resume_unwind(panic))
+        })
+        .map_err(EvaluationError::Runtime)
     }
 
     pub fn continue_with(self, syntax_tree: SyntaxTree) -> Self {
-        Compilation::new_internal(Some(self), syntax_tree)
+        let options = self.options;
+        self.continue_with_options(syntax_tree, options)
+    }
+
+    /// Like `continue_with`, but the new submission is compiled with different options.
+    pub fn continue_with_options(
+        self,
+        syntax_tree: SyntaxTree,
+        options: CompilationOptions,
+    ) -> Self {
+        Compilation::new_internal(options, Some(self), syntax_tree)
     }
 
     pub(crate) fn global_scope(&mut self) -> Arc<BoundGlobalScope> {
         if self.global_scope.is_none() {
-            self.global_scope = Some(Arc::new(Binder::bind_global_scope(
+            let global_scope = Binder::bind_global_scope(
+                self.options,
                 self.previous.as_mut().map(|p| p.global_scope()),
                 self.syntax_tree.root(),
-            )));
+            );
+            // a submission with errors is never evaluated
+            let valid = self.syntax_tree.diagnostics().next().is_none()
+                && global_scope.diagnostics().next().is_none();
+            if valid {
+                self.function_bodies = Arc::new(
+                    global_scope
+                        .function_bodies()
+                        .map(|(function, body)| {
+                            let body = Lowerer::lower(&BoundStatement::Block(body.clone()));
+                            (function.clone(), body)
+                        })
+                        .collect(),
+                );
+            }
+            self.global_scope = Some(Arc::new(global_scope));
         }
 
         self.global_scope.clone().unwrap()
     }
+
+    /// The bodies of every function declared in this submission or any previous one.
+    /// Functions redeclared by a later submission shadow the earlier ones.
+    fn function_bodies(&self) -> HashMap<FunctionSymbol, &BoundBlockStatement> {
+        let mut bodies = HashMap::new();
+        let mut compilation = Some(self);
+        while let Some(c) = compilation {
+            for (function, body) in c.function_bodies.iter() {
+                bodies.entry(function.clone()).or_insert(body);
+            }
+            compilation = c.previous.as_deref();
+        }
+        bodies
+    }
 }
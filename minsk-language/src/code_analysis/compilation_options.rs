@@ -0,0 +1,7 @@
+#[derive(Debug, Copy, Clone, Default)]
+pub struct CompilationOptions {
+    /// Treat integer overflow as an error instead of wrapping around. Overflow between
+    /// constants is reported by the binder, anything else fails at runtime. Without it,
+    /// constants wrap when folded exactly as they would at runtime, and nothing is reported.
+    pub checked_arithmetic: bool,
+}
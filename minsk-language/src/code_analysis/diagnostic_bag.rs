@@ -19,6 +19,10 @@ impl DiagnosticBag {
         self.diagnostics.iter().cloned()
     }
 
+    pub fn extend<I: Iterator<Item = Diagnostic>>(&mut self, diagnostics: I) {
+        self.diagnostics.extend(diagnostics);
+    }
+
     pub fn report<S: AsRef<str>>(&mut self, span: TextSpan, message: S) {
         self.diagnostics.push(Diagnostic {
             span,
@@ -30,6 +34,35 @@ impl DiagnosticBag {
         self.report(span, format!("The number {} isn't a valid {}.", text, ty));
     }
 
+    pub fn report_number_overflow(&mut self, span: TextSpan, text: &str, ty: MinskType) {
+        self.report(
+            span,
+            format!("The number {} is too large for {}.", text, ty),
+        );
+    }
+
+    pub fn report_invalid_digit(&mut self, span: TextSpan, c: char, radix_name: &str) {
+        self.report(span, format!("'{}' isn't a valid {} digit.", c, radix_name));
+    }
+
+    pub fn report_trailing_separator(&mut self, span: TextSpan) {
+        self.report(span, "A digit separator can't end a number.");
+    }
+
+    pub fn report_misplaced_separator(&mut self, span: TextSpan) {
+        self.report(span, "A digit separator must be between two digits.");
+    }
+
+    pub fn report_missing_digits(&mut self, span: TextSpan, prefix: &str, radix_name: &str) {
+        self.report(
+            span,
+            format!(
+                "The prefix '{}' must be followed by at least one {} digit.",
+                prefix, radix_name
+            ),
+        );
+    }
+
     pub fn report_bad_character(&mut self, position: usize, c: char) {
         self.report(
             TextSpan {
@@ -40,6 +73,47 @@ impl DiagnosticBag {
         )
     }
 
+    pub fn report_unterminated_string(&mut self, position: usize) {
+        self.report(
+            TextSpan {
+                start: position,
+                end: position + 1,
+            },
+            "Unterminated string literal",
+        )
+    }
+
+    pub fn report_unterminated_char(&mut self, position: usize) {
+        self.report(
+            TextSpan {
+                start: position,
+                end: position + 1,
+            },
+            "Unterminated character literal",
+        )
+    }
+
+    pub fn report_invalid_char_length(&mut self, span: TextSpan) {
+        self.report(
+            span,
+            "A character literal must contain exactly one character",
+        );
+    }
+
+    pub fn report_unterminated_multi_line_comment(&mut self, position: usize) {
+        self.report(
+            TextSpan {
+                start: position,
+                end: position + 2,
+            },
+            "Unterminated multi-line comment",
+        )
+    }
+
+    pub fn report_invalid_escape_sequence(&mut self, span: TextSpan, text: &str) {
+        self.report(span, format!("Invalid escape sequence: '{}'", text));
+    }
+
     pub(crate) fn report_unexpected_token(
         &mut self,
         span: TextSpan,
@@ -53,6 +127,18 @@ impl DiagnosticBag {
         self.report(span, message);
     }
 
+    pub(crate) fn report_ambiguous_continuation(&mut self, span: TextSpan, text: &str) {
+        let message = format!(
+            "'{}' at the start of a line continues the expression on the previous line; end the previous statement with ';' to start a new one",
+            text
+        );
+        self.report(span, message);
+    }
+
+    pub(crate) fn report_stray_semicolon(&mut self, span: TextSpan) {
+        self.report(span, "Unexpected ';', which can only end a statement");
+    }
+
     pub(crate) fn report_undefined_unary_operator(
         &mut self,
         span: TextSpan,
@@ -95,6 +181,43 @@ impl DiagnosticBag {
         self.report(span, message);
     }
 
+    pub(crate) fn report_cannot_convert_implicitly(
+        &mut self,
+        span: TextSpan,
+        from_type: MinskType,
+        to_type: MinskType,
+    ) {
+        let message = format!(
+            "Cannot convert {} to {}. An explicit conversion exists (are you missing a conversion?)",
+            from_type, to_type
+        );
+        self.report(span, message);
+    }
+
+    pub(crate) fn report_constant_overflow(&mut self, span: TextSpan) {
+        self.report(
+            span,
+            "The operation overflows at compile time in checked mode",
+        );
+    }
+
+    pub(crate) fn report_mismatched_branch_types(
+        &mut self,
+        span: TextSpan,
+        when_true_type: MinskType,
+        when_false_type: MinskType,
+    ) {
+        let message = format!(
+            "Both branches of a conditional expression must have the same type, but were given {} and {}",
+            when_true_type, when_false_type
+        );
+        self.report(span, message);
+    }
+
+    pub(crate) fn report_zero_step(&mut self, span: TextSpan) {
+        self.report(span, "The step of a 'for' loop cannot be zero");
+    }
+
     pub(crate) fn report_variable_already_declared(&mut self, span: TextSpan, name: &str) {
         let message = format!("Variable '{}' has already been declared", name);
         self.report(span, message);
@@ -104,4 +227,143 @@ impl DiagnosticBag {
         let message = format!("Variable '{}' is immutable and cannot be assigned to", name);
         self.report(span, message);
     }
+
+    pub(crate) fn report_undefined_function(&mut self, span: TextSpan, name: &str) {
+        let message = format!("Function '{}' doesn't exist", name);
+        self.report(span, message);
+    }
+
+    pub(crate) fn report_undefined_type(&mut self, span: TextSpan, name: &str) {
+        let message = format!("Type '{}' doesn't exist", name);
+        self.report(span, message);
+    }
+
+    pub(crate) fn report_function_already_declared(&mut self, span: TextSpan, name: &str) {
+        let message = format!("Function '{}' has already been declared", name);
+        self.report(span, message);
+    }
+
+    pub(crate) fn report_function_named_like_type(&mut self, span: TextSpan, name: &str) {
+        let message = format!(
+            "Function '{}' has the name of a type, so calls to it would be conversions",
+            name
+        );
+        self.report(span, message);
+    }
+
+    pub(crate) fn report_parameter_already_declared(&mut self, span: TextSpan, name: &str) {
+        let message = format!("A parameter with the name '{}' already exists", name);
+        self.report(span, message);
+    }
+
+    pub(crate) fn report_wrong_argument_count(
+        &mut self,
+        span: TextSpan,
+        name: &str,
+        expected_count: usize,
+        actual_count: usize,
+    ) {
+        let message = format!(
+            "Function '{}' requires {} arguments but was given {}",
+            name, expected_count, actual_count
+        );
+        self.report(span, message);
+    }
+
+    pub(crate) fn report_wrong_argument_type(
+        &mut self,
+        span: TextSpan,
+        name: &str,
+        expected_type: MinskType,
+        actual_type: MinskType,
+    ) {
+        let message = format!(
+            "Parameter '{}' requires a value of type {} but was given a value of type {}",
+            name, expected_type, actual_type
+        );
+        self.report(span, message);
+    }
+
+    pub(crate) fn report_invalid_break_or_continue(&mut self, span: TextSpan, text: &str) {
+        self.report(
+            span,
+            format!("The '{}' keyword can only be used inside of loops", text),
+        );
+    }
+
+    pub(crate) fn report_unexpected_return_expression(&mut self, span: TextSpan) {
+        self.report(
+            span,
+            "An earlier 'return' ends the script without a value, so 'return' cannot be followed by an expression",
+        );
+    }
+
+    pub(crate) fn report_invalid_return_expression(&mut self, span: TextSpan, name: &str) {
+        let message = format!(
+            "Function '{}' doesn't return a value, so 'return' cannot be followed by an expression",
+            name
+        );
+        self.report(span, message);
+    }
+
+    pub(crate) fn report_missing_return_expression(&mut self, span: TextSpan, ty: MinskType) {
+        let message = format!("An expression of type {} is expected", ty);
+        self.report(span, message);
+    }
+
+    pub(crate) fn report_not_all_code_paths_return(&mut self, span: TextSpan) {
+        self.report(span, "Not all code paths return a value");
+    }
+
+    pub(crate) fn report_cannot_index(&mut self, span: TextSpan, ty: MinskType) {
+        let message = format!("Cannot index into a value of type {}", ty);
+        self.report(span, message);
+    }
+
+    pub(crate) fn report_expected_array(&mut self, span: TextSpan, ty: MinskType) {
+        let message = format!("An array is expected, but was given a value of type {}", ty);
+        self.report(span, message);
+    }
+
+    pub(crate) fn report_cannot_infer_empty_array(&mut self, span: TextSpan) {
+        self.report(
+            span,
+            "Cannot infer the type of an empty array without a type clause",
+        );
+    }
+
+    pub(crate) fn report_type_already_declared(&mut self, span: TextSpan, name: &str) {
+        let message = format!("Type '{}' has already been declared", name);
+        self.report(span, message);
+    }
+
+    pub(crate) fn report_field_already_declared(&mut self, span: TextSpan, name: &str) {
+        let message = format!("Field '{}' has already been declared", name);
+        self.report(span, message);
+    }
+
+    pub(crate) fn report_empty_struct(&mut self, span: TextSpan, name: &str) {
+        let message = format!("Struct '{}' must declare at least one field", name);
+        self.report(span, message);
+    }
+
+    pub(crate) fn report_undefined_field(&mut self, span: TextSpan, ty: MinskType, name: &str) {
+        let message = format!("Type {} doesn't have a field '{}'", ty, name);
+        self.report(span, message);
+    }
+
+    pub(crate) fn report_field_already_initialized(&mut self, span: TextSpan, name: &str) {
+        let message = format!("Field '{}' has already been initialized", name);
+        self.report(span, message);
+    }
+
+    pub(crate) fn report_missing_field_initializer(
+        &mut self,
+        span: TextSpan,
+        ty: MinskType,
+        name: &str,
+    ) {
+        let message = format!("Field '{}' of type {} must be initialized", name, ty);
+        self.report(span, message);
+    }
 }
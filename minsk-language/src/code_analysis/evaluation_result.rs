@@ -1,3 +1,11 @@
-use super::{diagnostic::Diagnostic, minsk_value::MinskValue};
+use super::{diagnostic::Diagnostic, minsk_value::MinskValue, runtime_error::RuntimeError};
 
-pub type EvaluationResult = Result<Option<MinskValue>, Vec<Diagnostic>>;
+#[derive(Debug, Clone)]
+pub enum EvaluationError {
+    /// The program didn't compile, so it was never run.
+    Diagnostics(Vec<Diagnostic>),
+    /// The program compiled but failed while running.
+    Runtime(RuntimeError),
+}
+
+pub type EvaluationResult = Result<Option<MinskValue>, EvaluationError>;
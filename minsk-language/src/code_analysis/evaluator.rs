@@ -1,17 +1,38 @@
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+    sync::Arc,
+};
+
+use parking_lot::RwLock;
 
 use super::{
     binding::{
+        bound_array_expression::BoundArrayExpression,
         bound_assignment_expression::BoundAssignmentExpression,
         bound_binary_expression::BoundBinaryExpression, bound_block_statement::BoundBlockStatement,
+        bound_call_expression::BoundCallExpression,
+        bound_compound_assignment_expression::BoundCompoundAssignmentExpression,
+        bound_conditional_expression::BoundConditionalExpression,
+        bound_conversion_expression::BoundConversionExpression,
         bound_expression_statement::BoundExpressionStatement,
-        bound_for_statement::BoundForStatement, bound_if_statement::BoundIfStatement,
-        bound_statement::BoundStatement, bound_unary_expression::BoundUnaryExpression,
+        bound_field_assignment_expression::BoundFieldAssignmentExpression,
+        bound_field_expression::BoundFieldExpression,
+        bound_index_assignment_expression::BoundIndexAssignmentExpression,
+        bound_index_expression::BoundIndexExpression, bound_return_statement::BoundReturnStatement,
+        bound_statement::BoundStatement, bound_struct_expression::BoundStructExpression,
+        bound_unary_expression::BoundUnaryExpression,
         bound_variable_declaration::BoundVariableDeclaration,
         bound_variable_expression::BoundVariableExpression,
-        bound_while_statement::BoundWhileStatement,
     },
-    minsk_value::MinskValue,
+    built_in_functions,
+    compilation_options::CompilationOptions,
+    function_symbol::FunctionSymbol,
+    host::Host,
+    minsk_type::MinskType,
+    minsk_value::{ArrayElements, MinskValue, StructFields},
+    runtime_error::RuntimeError,
+    text::text_span::TextSpan,
     variable_symbol::VariableSymbol,
 };
 
@@ -21,90 +42,188 @@ use super::binding::{
     bound_unary_operator_kind::BoundUnaryOperatorKind,
 };
 
+/// How deeply calls can nest before evaluation gives up, well before the evaluator's own
+/// recursion runs out of the stack that `Compilation::evaluate` gives it.
+const MAX_CALL_DEPTH: usize = 1000;
+
 pub struct Evaluator<'compilation> {
-    variables: &'compilation mut HashMap<VariableSymbol, MinskValue>,
+    functions: HashMap<FunctionSymbol, &'compilation BoundBlockStatement>,
+    /// Functions declared by the submission being evaluated, as opposed to earlier ones.
+    submission_functions: HashSet<FunctionSymbol>,
+    globals: &'compilation mut HashMap<VariableSymbol, MinskValue>,
+    host: &'compilation mut dyn Host,
+    options: CompilationOptions,
+    locals: Vec<HashMap<VariableSymbol, MinskValue>>,
     last_value: Option<MinskValue>,
 }
 
 impl<'compilation> Evaluator<'compilation> {
-    pub fn new(variables: &'compilation mut HashMap<VariableSymbol, MinskValue>) -> Self {
+    pub fn new(
+        functions: HashMap<FunctionSymbol, &'compilation BoundBlockStatement>,
+        submission_functions: HashSet<FunctionSymbol>,
+        globals: &'compilation mut HashMap<VariableSymbol, MinskValue>,
+        host: &'compilation mut dyn Host,
+        options: CompilationOptions,
+    ) -> Self {
         Self {
-            variables,
+            functions,
+            submission_functions,
+            globals,
+            host,
+            options,
+            locals: vec![],
             last_value: None,
         }
     }
 
-    pub fn evaluate(&mut self, root: &BoundStatement) -> Option<MinskValue> {
-        self.evaluate_statement(root);
-        self.last_value.clone()
+    pub fn evaluate(
+        &mut self,
+        body: &BoundBlockStatement,
+    ) -> Result<Option<MinskValue>, RuntimeError> {
+        // the script's own frame holds the temporaries the lowerer introduces at global scope
+        self.locals.push(HashMap::new());
+        // a `return` at global scope ends the script early with its value
+        let returned = self.evaluate_statements(body)?;
+        Ok(returned.or_else(|| self.last_value.clone()))
     }
 
-    fn evaluate_statement(&mut self, statement: &BoundStatement) {
-        match statement {
-            BoundStatement::Block(b) => self.evaluate_block_statement(b),
-            BoundStatement::Expression(e) => self.evaluate_expression_statement(e),
-            BoundStatement::For(f) => self.evaluate_for_statement(f),
-            BoundStatement::If(i) => self.evaluate_if_statement(i),
-            BoundStatement::VariableDeclaration(v) => self.evaluate_variable_declaration(v),
-            BoundStatement::While(w) => self.evaluate_while_statement(w),
+    fn assign(&mut self, variable: &VariableSymbol, value: MinskValue) {
+        if variable.is_local() {
+            self.locals
+                .last_mut()
+                .unwrap()
+                .insert(variable.clone(), value);
+        } else {
+            self.globals.insert(variable.clone(), value);
         }
     }
 
-    fn evaluate_for_statement(&mut self, f: &BoundForStatement) {
-        let lower_bound = self.evaluate_expression(f.lower_bound());
-        let upper_bound = self.evaluate_expression(f.upper_bound());
-        self.variables
-            .insert(f.variable().clone(), lower_bound.clone());
-
-        for i in lower_bound.as_integer().unwrap()..=upper_bound.as_integer().unwrap() {
-            self.variables
-                .insert(f.variable().clone(), MinskValue::Integer(i));
-            self.evaluate_statement(f.body());
-        }
+    /// A global can be read before its declaration has run, when a function that uses it is
+    /// called earlier in the script, so a missing value is an error rather than a bug.
+    fn lookup(
+        &self,
+        variable: &VariableSymbol,
+        span: TextSpan,
+    ) -> Result<MinskValue, RuntimeError> {
+        let value = if variable.is_local() {
+            self.locals.last().unwrap().get(variable)
+        } else {
+            self.globals.get(variable)
+        };
+        value
+            .cloned()
+            .ok_or_else(|| RuntimeError::unassigned_variable(span, variable.name()))
     }
 
-    fn evaluate_while_statement(&mut self, w: &BoundWhileStatement) {
-        while self
-            .evaluate_expression(w.condition())
-            .as_boolean()
-            .unwrap()
-        {
-            self.evaluate_statement(w.body());
+    /// Runs a lowered block of statements, returning the value of the `return` statement
+    /// that ended it, if any.
+    fn evaluate_statements(
+        &mut self,
+        body: &BoundBlockStatement,
+    ) -> Result<Option<MinskValue>, RuntimeError> {
+        let statements = body.statements();
+        let labels = statements
+            .iter()
+            .enumerate()
+            .filter_map(|(i, s)| match s {
+                BoundStatement::Label(l) => Some((l.label(), i + 1)),
+                _ => None,
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut index = 0;
+        while index < statements.len() {
+            match &statements[index] {
+                BoundStatement::Expression(e) => self.evaluate_expression_statement(e)?,
+                BoundStatement::VariableDeclaration(v) => self.evaluate_variable_declaration(v)?,
+                BoundStatement::Goto(g) => {
+                    index = labels[g.label()];
+                    continue;
+                }
+                BoundStatement::ConditionalGoto(c) => {
+                    let condition = self.evaluate_expression(c.condition())?;
+                    if condition.as_boolean().unwrap() == c.jump_if_true() {
+                        index = labels[c.label()];
+                        continue;
+                    }
+                }
+                BoundStatement::Label(_) => {}
+                BoundStatement::Return(r) => return self.evaluate_return_statement(r).map(Some),
+                s @ (BoundStatement::Block(_)
+                | BoundStatement::DoWhile(_)
+                | BoundStatement::For(_)
+                | BoundStatement::If(_)
+                | BoundStatement::While(_)) => {
+                    unreachable!("{:?} should have been lowered", s)
+                }
+            }
+            index += 1;
         }
+        Ok(None)
     }
 
-    fn evaluate_if_statement(&mut self, i: &BoundIfStatement) {
-        let condition = self.evaluate_expression(i.condition());
-        if condition.as_boolean().unwrap() {
-            self.evaluate_statement(i.then_statement());
-        } else if let Some(e) = i.else_statement() {
-            self.evaluate_statement(e);
+    fn evaluate_return_statement(
+        &mut self,
+        r: &BoundReturnStatement,
+    ) -> Result<MinskValue, RuntimeError> {
+        match r.expression() {
+            Some(e) => self.evaluate_expression(e),
+            None => Ok(MinskValue::Null),
         }
     }
 
-    fn evaluate_variable_declaration(&mut self, v: &BoundVariableDeclaration) {
-        let value = self.evaluate_expression(v.initializer());
-        self.variables.insert(v.variable().clone(), value.clone());
+    fn evaluate_variable_declaration(
+        &mut self,
+        v: &BoundVariableDeclaration,
+    ) -> Result<(), RuntimeError> {
+        let value = self.evaluate_expression(v.initializer())?;
+        self.assign(v.variable(), value.clone());
         self.last_value = Some(value);
+        Ok(())
     }
 
-    fn evaluate_block_statement(&mut self, b: &BoundBlockStatement) {
-        for statement in b.statements() {
-            self.evaluate(statement);
-        }
-    }
-
-    fn evaluate_expression_statement(&mut self, e: &BoundExpressionStatement) {
-        self.last_value = Some(self.evaluate_expression(e.expression()));
+    fn evaluate_expression_statement(
+        &mut self,
+        e: &BoundExpressionStatement,
+    ) -> Result<(), RuntimeError> {
+        self.last_value = Some(self.evaluate_expression(e.expression())?);
+        Ok(())
     }
 
-    fn evaluate_expression(&mut self, root: &BoundExpression) -> MinskValue {
+    fn evaluate_expression(&mut self, root: &BoundExpression) -> Result<MinskValue, RuntimeError> {
         match root {
-            BoundExpression::Literal(lit) => self.evaluate_literal_expression(lit),
+            BoundExpression::Literal(lit) => Ok(self.evaluate_literal_expression(lit)),
             BoundExpression::Unary(u) => self.evaluate_unary_expression(u),
             BoundExpression::Binary(b) => self.evaluate_binary_expression(b),
             BoundExpression::Variable(v) => self.evaluate_variable_expression(v),
             BoundExpression::Assignment(a) => self.evaluate_assignment_expression(a),
+            BoundExpression::Call(c) => self.evaluate_call_expression(c),
+            BoundExpression::CompoundAssignment(c) => {
+                self.evaluate_compound_assignment_expression(c)
+            }
+            BoundExpression::Conversion(c) => self.evaluate_conversion_expression(c),
+            BoundExpression::Array(a) => self.evaluate_array_expression(a),
+            BoundExpression::Index(i) => {
+                let (elements, index) = self.evaluate_index_target(i)?;
+                let element = elements.read()[index].clone();
+                Ok(element)
+            }
+            BoundExpression::IndexAssignment(a) => self.evaluate_index_assignment_expression(a),
+            BoundExpression::Struct(s) => self.evaluate_struct_expression(s),
+            BoundExpression::Field(f) => {
+                let fields = self.evaluate_field_target(f)?;
+                let field = fields.read()[f.index].clone();
+                Ok(field)
+            }
+            BoundExpression::FieldAssignment(a) => self.evaluate_field_assignment_expression(a),
+            BoundExpression::Conditional(c) => self.evaluate_conditional_expression(c),
+            BoundExpression::Step(s) => {
+                let step = self.evaluate_expression(&s.expression)?;
+                if step.as_integer() == Some(0) {
+                    return Err(RuntimeError::zero_step(s.span));
+                }
+                Ok(step)
+            }
         }
     }
 
@@ -112,40 +231,156 @@ impl<'compilation> Evaluator<'compilation> {
         lit.value.clone()
     }
 
-    fn evaluate_unary_expression(&mut self, u: &BoundUnaryExpression) -> MinskValue {
-        let operand = self.evaluate_expression(&u.operand);
-        match u.op.kind {
-            BoundUnaryOperatorKind::Identity => MinskValue::Integer(operand.as_integer().unwrap()),
-            BoundUnaryOperatorKind::Negation => MinskValue::Integer(-operand.as_integer().unwrap()),
+    fn evaluate_unary_expression(
+        &mut self,
+        u: &BoundUnaryExpression,
+    ) -> Result<MinskValue, RuntimeError> {
+        let operand = self.evaluate_expression(&u.operand)?;
+        Self::evaluate_unary_operator(
+            u.op.kind,
+            operand,
+            u.operator_span,
+            self.options.checked_arithmetic,
+        )
+    }
+
+    pub(crate) fn evaluate_unary_operator(
+        kind: BoundUnaryOperatorKind,
+        operand: MinskValue,
+        operator_span: TextSpan,
+        checked: bool,
+    ) -> Result<MinskValue, RuntimeError> {
+        Ok(match kind {
+            BoundUnaryOperatorKind::Identity => operand,
+            BoundUnaryOperatorKind::Negation if operand.is_float() => {
+                MinskValue::Float(-operand.as_float().unwrap())
+            }
+            BoundUnaryOperatorKind::Negation => Self::integral_result(
+                operand.ty(),
+                -operand.as_integral().unwrap(),
+                operator_span,
+                checked,
+            )?,
             BoundUnaryOperatorKind::LogicalNegation => {
                 MinskValue::Boolean(!operand.as_boolean().unwrap())
             }
-        }
+            BoundUnaryOperatorKind::OnesComplement => {
+                MinskValue::wrapping_integral(operand.ty(), !operand.as_integral().unwrap())
+            }
+        })
     }
 
-    fn evaluate_binary_expression(&mut self, b: &BoundBinaryExpression) -> MinskValue {
-        let left = self.evaluate_expression(&b.left);
-        let right = self.evaluate_expression(&b.right);
+    fn evaluate_binary_expression(
+        &mut self,
+        b: &BoundBinaryExpression,
+    ) -> Result<MinskValue, RuntimeError> {
+        let left = self.evaluate_expression(&b.left)?;
         match b.op.kind {
-            BoundBinaryOperatorKind::Addition => MinskValue::Integer(
-                left.as_integer()
-                    .unwrap()
-                    .wrapping_add(right.as_integer().unwrap()),
-            ),
-            BoundBinaryOperatorKind::Subtraction => MinskValue::Integer(
-                left.as_integer()
-                    .unwrap()
-                    .wrapping_sub(right.as_integer().unwrap()),
+            BoundBinaryOperatorKind::LogicalAnd if !left.as_boolean().unwrap() => {
+                return Ok(MinskValue::Boolean(false));
+            }
+            BoundBinaryOperatorKind::LogicalOr if left.as_boolean().unwrap() => {
+                return Ok(MinskValue::Boolean(true));
+            }
+            _ => {}
+        }
+        let right = self.evaluate_expression(&b.right)?;
+        Self::evaluate_binary_operator(
+            b.op.kind,
+            left,
+            right,
+            b.span,
+            b.operator_span,
+            self.options.checked_arithmetic,
+        )
+    }
+
+    /// Division by zero is reported at `span`, overflow at `operator_span`.
+    pub(crate) fn evaluate_binary_operator(
+        kind: BoundBinaryOperatorKind,
+        left: MinskValue,
+        right: MinskValue,
+        span: TextSpan,
+        operator_span: TextSpan,
+        checked: bool,
+    ) -> Result<MinskValue, RuntimeError> {
+        let ty = left.ty();
+        let integral = |result: i128| Self::integral_result(ty, result, operator_span, checked);
+        // chars compare by their code points
+        let code_point = |value: &MinskValue| {
+            value
+                .as_integral()
+                .or_else(|| value.as_char().map(|c| c as i128))
+        };
+        let (l, r) = (code_point(&left), code_point(&right));
+        Ok(match kind {
+            BoundBinaryOperatorKind::Addition if left.is_string() => MinskValue::String(
+                left.as_string().unwrap().to_string() + right.as_string().unwrap(),
             ),
-            BoundBinaryOperatorKind::Multiplication => MinskValue::Integer(
-                left.as_integer()
-                    .unwrap()
-                    .wrapping_mul(right.as_integer().unwrap()),
+            BoundBinaryOperatorKind::Addition if left.is_float() => {
+                MinskValue::Float(left.as_float().unwrap() + right.as_float().unwrap())
+            }
+            BoundBinaryOperatorKind::Subtraction if left.is_float() => {
+                MinskValue::Float(left.as_float().unwrap() - right.as_float().unwrap())
+            }
+            BoundBinaryOperatorKind::Multiplication if left.is_float() => {
+                MinskValue::Float(left.as_float().unwrap() * right.as_float().unwrap())
+            }
+            // floats divide by zero to an infinity or NaN rather than failing
+            BoundBinaryOperatorKind::Division if left.is_float() => {
+                MinskValue::Float(left.as_float().unwrap() / right.as_float().unwrap())
+            }
+            BoundBinaryOperatorKind::Modulo if left.is_float() => {
+                MinskValue::Float(left.as_float().unwrap() % right.as_float().unwrap())
+            }
+            BoundBinaryOperatorKind::LessThan if left.is_float() => {
+                MinskValue::Boolean(left.as_float().unwrap() < right.as_float().unwrap())
+            }
+            BoundBinaryOperatorKind::LessOrEquals if left.is_float() => {
+                MinskValue::Boolean(left.as_float().unwrap() <= right.as_float().unwrap())
+            }
+            BoundBinaryOperatorKind::GreaterThan if left.is_float() => {
+                MinskValue::Boolean(left.as_float().unwrap() > right.as_float().unwrap())
+            }
+            BoundBinaryOperatorKind::GreaterOrEquals if left.is_float() => {
+                MinskValue::Boolean(left.as_float().unwrap() >= right.as_float().unwrap())
+            }
+            BoundBinaryOperatorKind::Addition => integral(l.unwrap() + r.unwrap())?,
+            BoundBinaryOperatorKind::Subtraction => integral(l.unwrap() - r.unwrap())?,
+            BoundBinaryOperatorKind::Multiplication => integral(l.unwrap() * r.unwrap())?,
+            BoundBinaryOperatorKind::Division => {
+                if r.unwrap() == 0 {
+                    return Err(RuntimeError::division_by_zero(span));
+                }
+                integral(l.unwrap() / r.unwrap())?
+            }
+            BoundBinaryOperatorKind::Modulo => {
+                if r.unwrap() == 0 {
+                    return Err(RuntimeError::division_by_zero(span));
+                }
+                integral(l.unwrap() % r.unwrap())?
+            }
+            BoundBinaryOperatorKind::BitwiseAnd if left.is_boolean() => {
+                MinskValue::Boolean(left.as_boolean().unwrap() & right.as_boolean().unwrap())
+            }
+            BoundBinaryOperatorKind::BitwiseAnd => integral(l.unwrap() & r.unwrap())?,
+            BoundBinaryOperatorKind::BitwiseOr if left.is_boolean() => {
+                MinskValue::Boolean(left.as_boolean().unwrap() | right.as_boolean().unwrap())
+            }
+            BoundBinaryOperatorKind::BitwiseOr => integral(l.unwrap() | r.unwrap())?,
+            BoundBinaryOperatorKind::BitwiseXor if left.is_boolean() => {
+                MinskValue::Boolean(left.as_boolean().unwrap() ^ right.as_boolean().unwrap())
+            }
+            BoundBinaryOperatorKind::BitwiseXor => integral(l.unwrap() ^ r.unwrap())?,
+            // like C#, the shift count is masked to the width of the shifted type, and
+            // shifting never overflows
+            BoundBinaryOperatorKind::LeftShift => MinskValue::wrapping_integral(
+                ty,
+                l.unwrap() << (r.unwrap() & (ty.bits() - 1) as i128),
             ),
-            BoundBinaryOperatorKind::Division => MinskValue::Integer(
-                left.as_integer()
-                    .unwrap()
-                    .wrapping_div(right.as_integer().unwrap()),
+            BoundBinaryOperatorKind::RightShift => MinskValue::wrapping_integral(
+                ty,
+                l.unwrap() >> (r.unwrap() & (ty.bits() - 1) as i128),
             ),
             BoundBinaryOperatorKind::Equality => MinskValue::Boolean(left == right),
             BoundBinaryOperatorKind::Inequality => MinskValue::Boolean(left != right),
@@ -155,43 +390,344 @@ impl<'compilation> Evaluator<'compilation> {
             BoundBinaryOperatorKind::LogicalOr => {
                 MinskValue::Boolean(left.as_boolean().unwrap() || right.as_boolean().unwrap())
             }
-            BoundBinaryOperatorKind::LessThan => {
-                MinskValue::Boolean(left.as_integer().unwrap() < right.as_integer().unwrap())
-            }
-            BoundBinaryOperatorKind::LessOrEquals => {
-                MinskValue::Boolean(left.as_integer().unwrap() <= right.as_integer().unwrap())
+            BoundBinaryOperatorKind::LessThan => MinskValue::Boolean(l < r),
+            BoundBinaryOperatorKind::LessOrEquals => MinskValue::Boolean(l <= r),
+            BoundBinaryOperatorKind::GreaterThan => MinskValue::Boolean(l > r),
+            BoundBinaryOperatorKind::GreaterOrEquals => MinskValue::Boolean(l >= r),
+        })
+    }
+
+    /// Narrows the result of integral arithmetic, which is done in `i128` so it cannot
+    /// overflow itself, back to `ty`.
+    fn integral_result(
+        ty: MinskType,
+        result: i128,
+        operator_span: TextSpan,
+        checked: bool,
+    ) -> Result<MinskValue, RuntimeError> {
+        if !checked {
+            return Ok(MinskValue::wrapping_integral(ty, result));
+        }
+        MinskValue::integral(ty, result).ok_or_else(|| RuntimeError::overflow(operator_span))
+    }
+
+    fn evaluate_variable_expression(
+        &mut self,
+        v: &BoundVariableExpression,
+    ) -> Result<MinskValue, RuntimeError> {
+        self.lookup(&v.variable, v.span)
+    }
+
+    fn evaluate_assignment_expression(
+        &mut self,
+        a: &BoundAssignmentExpression,
+    ) -> Result<MinskValue, RuntimeError> {
+        let value = self.evaluate_expression(&a.expression)?;
+        self.assign(&a.variable, value.clone());
+        Ok(value)
+    }
+
+    fn evaluate_compound_assignment_expression(
+        &mut self,
+        c: &BoundCompoundAssignmentExpression,
+    ) -> Result<MinskValue, RuntimeError> {
+        let previous = self.lookup(&c.variable, c.span)?;
+        let right = self.evaluate_expression(&c.expression)?;
+        let value = Self::evaluate_binary_operator(
+            c.op.kind,
+            previous.clone(),
+            right,
+            c.span,
+            c.operator_span,
+            self.options.checked_arithmetic,
+        )?;
+        self.assign(&c.variable, value.clone());
+        Ok(if c.returns_previous_value {
+            previous
+        } else {
+            value
+        })
+    }
+
+    fn evaluate_conversion_expression(
+        &mut self,
+        c: &BoundConversionExpression,
+    ) -> Result<MinskValue, RuntimeError> {
+        let value = self.evaluate_expression(&c.expression)?;
+        Self::evaluate_conversion(value, c.ty, c.span, self.options.checked_arithmetic)
+    }
+
+    /// Narrowing integral conversions wrap, or overflow in checked mode.
+    pub(crate) fn evaluate_conversion(
+        value: MinskValue,
+        ty: MinskType,
+        span: TextSpan,
+        checked: bool,
+    ) -> Result<MinskValue, RuntimeError> {
+        Ok(match (ty, value) {
+            (MinskType::String, value) => MinskValue::String(value.to_string()),
+            (MinskType::Boolean, MinskValue::String(s)) => match s.parse() {
+                Ok(b) => MinskValue::Boolean(b),
+                Err(_) => return Err(RuntimeError::invalid_conversion(span, &s, ty)),
+            },
+            (MinskType::Boolean, value) => MinskValue::Boolean(value.as_integral().unwrap() != 0),
+            (MinskType::Float, MinskValue::String(s)) => match s.parse::<f64>() {
+                Ok(f) => MinskValue::Float(f),
+                Err(_) => return Err(RuntimeError::invalid_conversion(span, &s, ty)),
+            },
+            (MinskType::Float, value) => MinskValue::Float(value.as_integral().unwrap() as f64),
+            (MinskType::Char, MinskValue::String(s)) => {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => MinskValue::Char(c),
+                    _ => return Err(RuntimeError::invalid_conversion(span, &s, ty)),
+                }
             }
-            BoundBinaryOperatorKind::GreaterThan => {
-                MinskValue::Boolean(left.as_integer().unwrap() > right.as_integer().unwrap())
+            // only valid code points convert, whatever the arithmetic mode
+            (MinskType::Char, value) => {
+                let i = value.as_integral().unwrap();
+                match u32::try_from(i).ok().and_then(char::from_u32) {
+                    Some(c) => MinskValue::Char(c),
+                    None => return Err(RuntimeError::invalid_conversion(span, &i.to_string(), ty)),
+                }
             }
-            BoundBinaryOperatorKind::GreaterOrEquals => {
-                MinskValue::Boolean(left.as_integer().unwrap() >= right.as_integer().unwrap())
+            (_, MinskValue::Char(c)) => Self::integral_result(ty, c as i128, span, checked)?,
+            (_, MinskValue::String(s)) => {
+                match s
+                    .parse::<i128>()
+                    .ok()
+                    .and_then(|i| MinskValue::integral(ty, i))
+                {
+                    Some(value) => value,
+                    None => return Err(RuntimeError::invalid_conversion(span, &s, ty)),
+                }
             }
+            (_, MinskValue::Boolean(b)) => MinskValue::integral(ty, b as i128).unwrap(),
+            // truncates toward zero, and outside checked mode saturates at the ends of the range
+            (_, MinskValue::Float(f)) => match MinskValue::integral(ty, f as i128) {
+                Some(value) if !f.is_nan() => value,
+                _ if checked => return Err(RuntimeError::overflow(span)),
+                _ => MinskValue::saturating_integral(ty, f as i128),
+            },
+            (_, value) => Self::integral_result(ty, value.as_integral().unwrap(), span, checked)?,
+        })
+    }
+
+    fn evaluate_array_expression(
+        &mut self,
+        a: &BoundArrayExpression,
+    ) -> Result<MinskValue, RuntimeError> {
+        let elements = a
+            .elements
+            .iter()
+            .map(|e| self.evaluate_expression(e))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(MinskValue::Array(a.ty, Arc::new(RwLock::new(elements))))
+    }
+
+    /// Evaluates the array and the index, checking the index is in bounds.
+    fn evaluate_index_target(
+        &mut self,
+        i: &BoundIndexExpression,
+    ) -> Result<(ArrayElements, usize), RuntimeError> {
+        let array = self.evaluate_expression(&i.array)?;
+        let index = self.evaluate_expression(&i.index)?.as_integer().unwrap();
+        let elements = array.as_array().unwrap().clone();
+        let length = elements.read().len();
+        if index < 0 || index as usize >= length {
+            return Err(RuntimeError::index_out_of_bounds(
+                i.index_span,
+                index,
+                length,
+            ));
         }
+        Ok((elements, index as usize))
     }
 
-    fn evaluate_variable_expression(&mut self, v: &BoundVariableExpression) -> MinskValue {
-        self.variables.get(&v.variable).unwrap().clone()
+    fn evaluate_index_assignment_expression(
+        &mut self,
+        a: &BoundIndexAssignmentExpression,
+    ) -> Result<MinskValue, RuntimeError> {
+        let (elements, index) = self.evaluate_index_target(&a.target)?;
+        let mut value = self.evaluate_expression(&a.expression)?;
+        if let Some(op) = a.op {
+            let previous = elements.read()[index].clone();
+            value = Self::evaluate_binary_operator(
+                op.kind,
+                previous,
+                value,
+                a.span,
+                a.operator_span,
+                self.options.checked_arithmetic,
+            )?;
+        }
+        elements.write()[index] = value.clone();
+        Ok(value)
     }
 
-    fn evaluate_assignment_expression(&mut self, a: &BoundAssignmentExpression) -> MinskValue {
-        let value = self.evaluate_expression(&a.expression);
-        self.variables.insert(a.variable.clone(), value.clone());
-        value
+    fn evaluate_conditional_expression(
+        &mut self,
+        c: &BoundConditionalExpression,
+    ) -> Result<MinskValue, RuntimeError> {
+        // only the chosen branch runs
+        if self
+            .evaluate_expression(&c.condition)?
+            .as_boolean()
+            .unwrap()
+        {
+            self.evaluate_expression(&c.when_true)
+        } else {
+            self.evaluate_expression(&c.when_false)
+        }
+    }
+
+    fn evaluate_struct_expression(
+        &mut self,
+        s: &BoundStructExpression,
+    ) -> Result<MinskValue, RuntimeError> {
+        let fields = s
+            .fields
+            .iter()
+            .map(|f| self.evaluate_expression(f))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(MinskValue::Struct(s.ty, Arc::new(RwLock::new(fields))))
+    }
+
+    fn evaluate_field_target(
+        &mut self,
+        f: &BoundFieldExpression,
+    ) -> Result<StructFields, RuntimeError> {
+        let value = self.evaluate_expression(&f.expression)?;
+        Ok(value.as_struct().unwrap().clone())
+    }
+
+    fn evaluate_field_assignment_expression(
+        &mut self,
+        a: &BoundFieldAssignmentExpression,
+    ) -> Result<MinskValue, RuntimeError> {
+        let fields = self.evaluate_field_target(&a.target)?;
+        let index = a.target.index;
+        let mut value = self.evaluate_expression(&a.expression)?;
+        if let Some(op) = a.op {
+            let previous = fields.read()[index].clone();
+            value = Self::evaluate_binary_operator(
+                op.kind,
+                previous,
+                value,
+                a.span,
+                a.operator_span,
+                self.options.checked_arithmetic,
+            )?;
+        }
+        fields.write()[index] = value.clone();
+        Ok(value)
+    }
+
+    fn evaluate_call_expression(
+        &mut self,
+        c: &BoundCallExpression,
+    ) -> Result<MinskValue, RuntimeError> {
+        let mut frame = HashMap::new();
+        for (argument, parameter) in c.arguments.iter().zip(c.function.parameters()) {
+            let value = self.evaluate_expression(argument)?;
+            frame.insert(parameter.variable(), value);
+        }
+
+        let body = match self.functions.get(&c.function) {
+            Some(body) => *body,
+            None => return self.evaluate_built_in_function(c, frame),
+        };
+        // the first frame is the script's own
+        if self.locals.len() > MAX_CALL_DEPTH {
+            return Err(RuntimeError::stack_overflow(c.span, MAX_CALL_DEPTH));
+        }
+        self.locals.push(frame);
+        let result = self.evaluate_statements(body);
+        self.locals.pop();
+        match result {
+            Ok(value) => Ok(value.unwrap_or(MinskValue::Null)),
+            // the body belongs to an earlier submission's source text,
+            // so point at the call instead
+            Err(error) if !self.submission_functions.contains(&c.function) => Err(RuntimeError {
+                span: c.span,
+                ..error
+            }),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn evaluate_built_in_function(
+        &mut self,
+        c: &BoundCallExpression,
+        mut arguments: HashMap<VariableSymbol, MinskValue>,
+    ) -> Result<MinskValue, RuntimeError> {
+        let mut argument = |index: usize| {
+            arguments
+                .remove(&c.function.parameters()[index].variable())
+                .unwrap()
+        };
+        Ok(if c.function == built_in_functions::print() {
+            self.host.print(argument(0).as_string().unwrap());
+            MinskValue::Null
+        } else if c.function == built_in_functions::input() {
+            MinskValue::String(self.host.input())
+        } else if c.function == built_in_functions::rnd() {
+            let max = argument(0).as_integer().unwrap();
+            if max <= 0 {
+                return Err(RuntimeError::invalid_argument(
+                    c.span,
+                    format!(
+                        "The maximum passed to rnd must be positive, but was {}",
+                        max
+                    ),
+                ));
+            }
+            MinskValue::Integer(self.host.random(max))
+        } else if c.function == built_in_functions::len() {
+            MinskValue::Integer(argument(0).as_array().unwrap().read().len() as i32)
+        } else {
+            unreachable!("{} has no body", c.function.name())
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::io::{BufRead, BufReader};
+    use std::{
+        collections::VecDeque,
+        io::{BufRead, BufReader},
+    };
 
     use crate::code_analysis::{
-        compilation::Compilation, syntax::syntax_tree::SyntaxTree, text::text_span::TextSpan,
+        compilation::Compilation, compilation_options::CompilationOptions,
+        evaluation_result::EvaluationError, runtime_error::RuntimeErrorKind,
+        syntax::syntax_tree::SyntaxTree, text::text_span::TextSpan,
     };
 
     use super::*;
     use spectral::prelude::*;
 
+    /// Feeds `input` to the program and collects what it prints.
+    #[derive(Default)]
+    struct TestHost {
+        input: VecDeque<String>,
+        output: Vec<String>,
+    }
+
+    impl Host for TestHost {
+        fn print(&mut self, text: &str) {
+            self.output.push(text.to_string());
+        }
+
+        fn input(&mut self) -> String {
+            self.input.pop_front().unwrap_or_default()
+        }
+
+        fn random(&mut self, max: i32) -> i32 {
+            max - 1
+        }
+    }
+
     #[derive(Debug)]
     struct AnnotatedText {
         text: String,
@@ -231,11 +767,20 @@ mod tests {
             let mut start_positions = Vec::<usize>::new();
 
             let mut position = 0;
+            // `\[` and `\]` stand for literal brackets, e.g. in array literals
+            let mut escaped = false;
             let text = text
                 .join("\n")
                 .chars()
                 .filter(|&c| {
-                    if c == '[' {
+                    if escaped {
+                        escaped = false;
+                        position += 1;
+                        true
+                    } else if c == '\\' {
+                        escaped = true;
+                        false
+                    } else if c == '[' {
                         start_positions.push(position);
                         false
                     } else if c == ']' {
@@ -279,6 +824,19 @@ mod tests {
         assert_has_diagnostics(text, diagnostics);
     }
 
+    #[test]
+    fn global_statements_report_redeclaration() {
+        let text = "
+            var x = 10
+            var [x] = 5
+            ";
+        let diagnostics = "
+            Variable 'x' has already been declared
+            ";
+
+        assert_has_diagnostics(text, diagnostics);
+    }
+
     #[test]
     fn name_expression_reports_undefined() {
         let text = "[x] * 10";
@@ -291,7 +849,7 @@ mod tests {
 
     #[test]
     fn name_expression_does_not_report_empty_token() {
-        let text = "[]";
+        let text = "1 + []";
         let diagnostics = "
             Unexpected token <EndOfFile>, expected <Identifier>
         ";
@@ -339,7 +897,7 @@ mod tests {
             }
         ";
         let diagnostics = "
-            Cannot convert Boolean to Integer
+            Cannot convert Boolean to Integer. An explicit conversion exists (are you missing a conversion?)
         ";
         assert_has_diagnostics(text, diagnostics);
     }
@@ -354,7 +912,7 @@ mod tests {
             }
             ";
         let diagnostics = "
-            Cannot convert Integer to Boolean
+            Cannot convert Integer to Boolean. An explicit conversion exists (are you missing a conversion?)
             ";
         assert_has_diagnostics(text, diagnostics);
     }
@@ -369,7 +927,23 @@ mod tests {
             }
             ";
         let diagnostics = "
-            Cannot convert Integer to Boolean
+            Cannot convert Integer to Boolean. An explicit conversion exists (are you missing a conversion?)
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn do_while_statement_reports_cannot_convert() {
+        let text = "
+            {
+                var x = 0
+                do
+                    x = 10
+                while [10]
+            }
+            ";
+        let diagnostics = "
+            Cannot convert Integer to Boolean. An explicit conversion exists (are you missing a conversion?)
             ";
         assert_has_diagnostics(text, diagnostics);
     }
@@ -384,7 +958,7 @@ mod tests {
             }
             ";
         let diagnostics = "
-            Cannot convert Boolean to Integer
+            Cannot convert Boolean to Integer. An explicit conversion exists (are you missing a conversion?)
             ";
         assert_has_diagnostics(text, diagnostics);
     }
@@ -399,26 +973,110 @@ mod tests {
             }
             ";
         let diagnostics = "
-            Cannot convert Boolean to Integer
+            Cannot convert Boolean to Integer. An explicit conversion exists (are you missing a conversion?)
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn compound_assignment_reports_cannot_assign() {
+        let text = "
+            {
+                let x = 10
+                x [+=] 1
+            }
+            ";
+        let diagnostics = "
+            Variable 'x' is immutable and cannot be assigned to
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn compound_assignment_reports_undefined_binary_operator() {
+        let text = "
+            {
+                var x = true
+                x [+=] 1
+            }
+            ";
+        let diagnostics = "
+            Binary operator '+=' is not defined for types Boolean and Integer
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn increment_reports_cannot_assign() {
+        let text = "
+            {
+                let x = 10
+                x[++]
+            }
+            ";
+        let diagnostics = "
+            Variable 'x' is immutable and cannot be assigned to
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn increment_reports_undefined_unary_operator() {
+        let text = "
+            {
+                var x = true
+                [--]x
+            }
+            ";
+        let diagnostics = "
+            Unary operator '--' is not defined for type Boolean
             ";
         assert_has_diagnostics(text, diagnostics);
     }
 
+    #[test]
+    fn modulo_reports_division_by_zero() {
+        assert_has_runtime_error(
+            "var x = 0 [10 % x]",
+            RuntimeErrorKind::DivisionByZero,
+            "Attempted to divide by zero",
+        );
+    }
+
+    #[test]
+    fn compound_division_by_zero_reports_runtime_error() {
+        assert_has_runtime_error(
+            "var x = 1 [x /= 0]",
+            RuntimeErrorKind::DivisionByZero,
+            "Attempted to divide by zero",
+        );
+    }
+
     fn assert_has_diagnostics(text: &str, diagnostics: &str) {
+        assert_has_diagnostics_with_options(CompilationOptions::default(), text, diagnostics);
+    }
+
+    fn assert_has_diagnostics_with_options(
+        options: CompilationOptions,
+        text: &str,
+        diagnostics: &str,
+    ) {
         let annotated_text = AnnotatedText::parse(text);
         let syntax_tree = SyntaxTree::parse(annotated_text.text.clone());
-        let mut compilation = Compilation::new(syntax_tree);
-        let result = compilation.evaluate(&mut HashMap::new());
+        let mut compilation = Compilation::with_options(syntax_tree, options);
+        let result = compilation.evaluate(&mut HashMap::new(), &mut TestHost::default());
         let expected_diagnostics = AnnotatedText::dedent(diagnostics);
-        asserting!("result is error").that(&result).is_err();
-        let result = result.unwrap_err();
+        let result = match result {
+            Err(EvaluationError::Diagnostics(diagnostics)) => diagnostics,
+            r => panic!("expected diagnostics, got {:?}", r),
+        };
 
         if annotated_text.spans.len() != expected_diagnostics.len() {
             panic!("mismatch between span count and diagnostic count");
         }
         asserting!("same number of diagnostics")
             .that(&result.len())
-            .is_equal_to(&expected_diagnostics.len());
+            .is_equal_to(expected_diagnostics.len());
         for (i, (diagnostic, span)) in expected_diagnostics
             .iter()
             .zip(annotated_text.spans)
@@ -431,10 +1089,40 @@ mod tests {
                 .is_equal_to(diagnostic);
             asserting!("spans match")
                 .that(&actual_span)
-                .is_equal_to(&span);
+                .is_equal_to(span);
         }
     }
 
+    fn assert_has_runtime_error(text: &str, kind: RuntimeErrorKind, message: &str) {
+        assert_has_runtime_error_with_options(CompilationOptions::default(), text, kind, message);
+    }
+
+    fn assert_has_runtime_error_with_options(
+        options: CompilationOptions,
+        text: &str,
+        kind: RuntimeErrorKind,
+        message: &str,
+    ) {
+        let annotated_text = AnnotatedText::parse(text);
+        let syntax_tree = SyntaxTree::parse(annotated_text.text.clone());
+        let result = Compilation::with_options(syntax_tree, options)
+            .evaluate(&mut HashMap::new(), &mut TestHost::default());
+        let error = match result {
+            Err(EvaluationError::Runtime(error)) => error,
+            r => panic!("expected a runtime error, got {:?}", r),
+        };
+
+        asserting!("kinds match")
+            .that(&error.kind)
+            .is_equal_to(kind);
+        asserting!("messages match")
+            .that(&error.message.as_str())
+            .is_equal_to(message);
+        asserting!("spans match")
+            .that(&vec![error.span])
+            .is_equal_to(annotated_text.spans);
+    }
+
     #[test]
     fn annotated_text_dedents_correctly() {
         let text = "
@@ -443,14 +1131,14 @@ mod tests {
             ";
         asserting!("strips indentation")
             .that(&AnnotatedText::dedent(text))
-            .is_equal_to(&vec!["test".to_string(), "set".to_string()]);
+            .is_equal_to(vec!["test".to_string(), "set".to_string()]);
     }
 
     fn try_evaluate(text: &str, expected: Option<MinskValue>) {
         let syntax_tree = SyntaxTree::parse(text.to_string());
 
-        let actual = Compilation::new(syntax_tree)
-            .evaluate(&mut HashMap::<VariableSymbol, MinskValue>::new());
+        let actual =
+            Compilation::new(syntax_tree).evaluate(&mut HashMap::new(), &mut TestHost::default());
 
         asserting!("evaluated value")
             .that(&actual)
@@ -458,6 +1146,949 @@ mod tests {
             .is_equal_to(&expected);
     }
 
+    #[test]
+    fn string_reports_unterminated() {
+        let text = "
+            [\"]abc
+            ";
+        let diagnostics = "
+            Unterminated string literal
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn multi_line_comment_reports_unterminated() {
+        let text = "
+            1 + 2 [/*] a /* b */
+            ";
+        let diagnostics = "
+            Unterminated multi-line comment
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn string_reports_invalid_escape_sequence() {
+        let text = "
+            \"a[\\\\q]b\"
+            ";
+        let diagnostics = "
+            Invalid escape sequence: '\\q'
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn bad_binary_operator_is_reported_for_strings() {
+        let text = "\"a\" [-] \"b\"";
+        let diagnostics = "
+            Binary operator '-' is not defined for types String and String
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn call_expression_reports_undefined_function() {
+        let text = "[f](1)";
+        let diagnostics = "
+            Function 'f' doesn't exist
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn call_expression_reports_wrong_argument_count() {
+        let text = "
+            function f(a: int): int { return a }
+            [f(1, 2)]
+            ";
+        let diagnostics = "
+            Function 'f' requires 1 arguments but was given 2
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn call_expression_reports_wrong_argument_type() {
+        let text = "
+            function f(a: int, b: bool): int { return a }
+            f([true], true)
+            ";
+        let diagnostics = "
+            Parameter 'a' requires a value of type Integer but was given a value of type Boolean
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn variable_declaration_reports_undefined_type() {
+        let text = "
+            var x: [foo] = 10
+            ";
+        let diagnostics = "
+            Type 'foo' doesn't exist
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn variable_declaration_reports_cannot_convert_initializer() {
+        let text = "
+            {
+                let x: bool = [10]
+                x
+            }
+            ";
+        let diagnostics = "
+            Cannot convert Integer to Boolean. An explicit conversion exists (are you missing a conversion?)
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn variable_declaration_uses_declared_type() {
+        let text = "
+            {
+                var x: string = [1]
+                x [+=] 1
+            }
+            ";
+        let diagnostics = "
+            Cannot convert Integer to String. An explicit conversion exists (are you missing a conversion?)
+            Binary operator '+=' is not defined for types String and Integer
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn variable_declaration_reports_cannot_convert_without_conversion() {
+        let text = "
+            function f() { }
+            var x: int = [f()]
+            ";
+        let diagnostics = "
+            Cannot convert Null to Integer
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn conversion_reports_cannot_convert() {
+        let text = "
+            function f() { }
+            [bool(f())]
+            ";
+        let diagnostics = "
+            Cannot convert Null to Boolean
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn number_reports_invalid() {
+        let text = "
+            [1e999] + [99999999999999999999] + 1_000[_] + 1.5e3[_]
+            1[_].5 + 1.[_]5 + 1[_]e5 + 1e[_]5 + 1.5e-[_]5 + 1[_]_0
+            ";
+        let diagnostics = "
+            The number 1e999 is too large for Float.
+            The number 99999999999999999999 is too large for Int64.
+            A digit separator can't end a number.
+            A digit separator can't end a number.
+            A digit separator must be between two digits.
+            A digit separator must be between two digits.
+            A digit separator must be between two digits.
+            A digit separator must be between two digits.
+            A digit separator must be between two digits.
+            A digit separator must be between two digits.
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn number_reports_invalid_radix_literal() {
+        let text = "
+            var a = [0x]
+            var b = 0b[_]
+            var c = 0b10[2]1
+            var d = 0o7[8]
+            var e = 0x1[g]
+            var f = [0x8000_0000_0000_0000]
+            var g = 0b1[_]
+            var h = 1[٣]
+            ";
+        let diagnostics = "
+            The prefix '0x' must be followed by at least one hexadecimal digit.
+            A digit separator can't end a number.
+            '2' isn't a valid binary digit.
+            '8' isn't a valid octal digit.
+            'g' isn't a valid hexadecimal digit.
+            The number 0x8000_0000_0000_0000 is too large for Int64.
+            A digit separator can't end a number.
+            bad character input: '٣'
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn float_reports_cannot_convert_implicitly() {
+        let text = "
+            {
+                var x = 1
+                x = [0.5]
+            }
+            ";
+        let diagnostics = "
+            Cannot convert Float to Integer. An explicit conversion exists (are you missing a conversion?)
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn sized_integer_reports_cannot_convert_implicitly() {
+        let text = "
+            {
+                var b: int8 = [200]
+                var i = 1
+                var s: int16 = [i]
+                var u: uint = [-1]
+                var l: int64 = 1
+                var x: int = [l]
+            }
+            ";
+        let diagnostics = "
+            Cannot convert Integer to Int8. An explicit conversion exists (are you missing a conversion?)
+            Cannot convert Integer to Int16. An explicit conversion exists (are you missing a conversion?)
+            Cannot convert Integer to UInt. An explicit conversion exists (are you missing a conversion?)
+            Cannot convert Int64 to Integer. An explicit conversion exists (are you missing a conversion?)
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn shift_reports_undefined_for_wide_count() {
+        let text = "
+            var l: int64 = 1
+            1 [<<] l
+            ";
+        let diagnostics = "
+            Binary operator '<<' is not defined for types Integer and Int64
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn array_reports_invalid_use() {
+        let text = "
+            {
+                var x = 1
+                var a = \\[1, [true]\\]
+                var y = [x]\\[0\\]
+                var e = [\\[\\]]
+                var n = len([x])
+                var b: \\[[foo]\\] = a
+            }
+            ";
+        let diagnostics = "
+            Cannot convert Boolean to Integer. An explicit conversion exists (are you missing a conversion?)
+            Cannot index into a value of type Integer
+            Cannot infer the type of an empty array without a type clause
+            An array is expected, but was given a value of type Integer
+            Type 'foo' doesn't exist
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn struct_reports_invalid_declaration() {
+        let text = "
+            struct Point { x: int, [x: bool] }
+            struct [Point] { y: int }
+            struct [int] { z: int }
+            struct [Empty] { }
+            struct Line { a: Point, b: [foo] }
+            1
+            ";
+        let diagnostics = "
+            Field 'x' has already been declared
+            Type 'Point' has already been declared
+            Type 'int' has already been declared
+            Struct 'Empty' must declare at least one field
+            Type 'foo' doesn't exist
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn struct_reports_missing_fields_of_empty_literal() {
+        let text = "
+            struct Single { x: int }
+            var s = [Single] { }
+            ";
+        let diagnostics = "
+            Field 'x' of type Single must be initialized
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn struct_reports_invalid_use() {
+        let text = "
+            struct Point { x: int, y: int }
+            var a = Point { x: 1, y: 2, [z]: 3 }
+            var b = [Point] { x: 1 }
+            var c = Point { x: 1, y: 2, [x: 3] }
+            var d = [Foo] { x: 1 }
+            var e = a.[z]
+            var f = 1.[x]
+            a.x = [\"x\"]
+            ";
+        let diagnostics = "
+            Type Point doesn't have a field 'z'
+            Field 'y' of type Point must be initialized
+            Field 'x' has already been initialized
+            Type 'Foo' doesn't exist
+            Type Point doesn't have a field 'z'
+            Type Integer doesn't have a field 'x'
+            Cannot convert String to Integer. An explicit conversion exists (are you missing a conversion?)
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn array_reports_index_out_of_bounds() {
+        for (text, message) in &[
+            (
+                "var a = \\[1, 2\\] a\\[[2]\\]",
+                "Index 2 is out of bounds for an array of length 2",
+            ),
+            (
+                "var a = \\[1, 2\\] a\\[[-1]\\] = 0",
+                "Index -1 is out of bounds for an array of length 2",
+            ),
+        ] {
+            assert_has_runtime_error(text, RuntimeErrorKind::IndexOutOfBounds, message);
+        }
+    }
+
+    #[test]
+    fn char_reports_invalid_literal() {
+        let text = "
+            var a = ['']
+            var b = ['ab']
+            var c = [']a
+            ";
+        let diagnostics = "
+            A character literal must contain exactly one character
+            A character literal must contain exactly one character
+            Unterminated character literal
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn char_reports_invalid_use() {
+        let text = "
+            var a = 'a' [+] 'b'
+            var i: int = ['a']
+            for c = 'a' to [10] { }
+            ";
+        let diagnostics = "
+            Binary operator '+' is not defined for types Char and Char
+            Cannot convert Char to Integer. An explicit conversion exists (are you missing a conversion?)
+            Cannot convert Integer to Char. An explicit conversion exists (are you missing a conversion?)
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn char_reports_invalid_conversion() {
+        for (text, message) in &[
+            ("var i = -1 [char(i)]", "Cannot convert '-1' to Char"),
+            ("var i = 55296 [char(i)]", "Cannot convert '55296' to Char"),
+            ("var s = \"ab\" [char(s)]", "Cannot convert 'ab' to Char"),
+        ] {
+            assert_has_runtime_error(text, RuntimeErrorKind::InvalidConversion, message);
+        }
+    }
+
+    #[test]
+    fn conditional_reports_invalid_use() {
+        let text = "
+            var x = [true ? 1 : \"a\"]
+            var y = [1] ? 2 : 3
+            ";
+        let diagnostics = "
+            Both branches of a conditional expression must have the same type, but were given Integer and String
+            Cannot convert Integer to Boolean. An explicit conversion exists (are you missing a conversion?)
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn statement_reports_ambiguous_continuation() {
+        let text = "
+            var a = 1
+            var b = 2
+            a = b
+            [-]a
+            a
+            [++]b
+            var c = (a
+                - b) + int(
+                -1);
+            -c
+            ";
+        let diagnostics = "
+            '-' at the start of a line continues the expression on the previous line; end the previous statement with ';' to start a new one
+            '++' at the start of a line continues the expression on the previous line; end the previous statement with ';' to start a new one
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn statement_continues_on_indented_line() {
+        let text = "
+            var a = 1
+            var b = 2
+            var t = a
+                + b
+            {
+                t = t
+                    - (a)
+            }
+            t
+            ";
+        try_evaluate(text, Some(MinskValue::Integer(2)));
+    }
+
+    #[test]
+    fn statement_reports_stray_semicolon() {
+        let text = "
+            var x = 1;[;]
+            {
+                x = 2;
+                [;]
+            }
+            ";
+        let diagnostics = "
+            Unexpected ';', which can only end a statement
+            Unexpected ';', which can only end a statement
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn for_statement_reports_invalid_step() {
+        let text = "
+            for i = 0 to 10 step [0] { }
+            for j = 10 to 0 step [-1 + 1] { }
+            for k = 0 to 10 step [\"2\"] { }
+            for c = 'a' to 'z' step ['b'] { }
+            ";
+        let diagnostics = "
+            The step of a 'for' loop cannot be zero
+            The step of a 'for' loop cannot be zero
+            Cannot convert String to Integer. An explicit conversion exists (are you missing a conversion?)
+            Cannot convert Char to Integer. An explicit conversion exists (are you missing a conversion?)
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn for_statement_reports_zero_step() {
+        assert_has_runtime_error(
+            "var s = 0 for i = 0 to 10 step [s * 2] { }",
+            RuntimeErrorKind::ZeroStep,
+            "The step of a 'for' loop cannot be zero",
+        );
+    }
+
+    #[test]
+    fn conversion_reports_invalid_conversion() {
+        assert_has_runtime_error(
+            "var s = \"abc\" [int(s)]",
+            RuntimeErrorKind::InvalidConversion,
+            "Cannot convert 'abc' to Integer",
+        );
+    }
+
+    #[test]
+    fn function_declaration_reports_global_declared_after_it() {
+        let text = "
+            function f(): int {
+                return [x]
+            }
+            var x = 1
+            ";
+        let diagnostics = "
+            Variable 'x' doesn't exist
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn function_reports_global_used_before_assignment() {
+        let text = "
+            f()
+            var x = 1
+            function f(): int {
+                return [x]
+            }
+            ";
+        assert_has_runtime_error(
+            text,
+            RuntimeErrorKind::UnassignedVariable,
+            "Variable 'x' is used before it is assigned a value",
+        );
+    }
+
+    #[test]
+    fn function_declaration_reports_undefined_type() {
+        let text = "
+            function f(a: [foo]) { }
+            1
+            ";
+        let diagnostics = "
+            Type 'foo' doesn't exist
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn function_declaration_reports_redeclaration() {
+        let text = "
+            function f() { }
+            function [f]() { }
+            f()
+            ";
+        let diagnostics = "
+            Function 'f' has already been declared
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn function_declaration_reports_type_name() {
+        let text = "
+            function [int](x: int): int { return x + 1 }
+            int(1)
+            ";
+        let diagnostics = "
+            Function 'int' has the name of a type, so calls to it would be conversions
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn function_declaration_reports_duplicate_parameter() {
+        let text = "
+            function f(a: int, [a: bool]) { }
+            f(1)
+            ";
+        let diagnostics = "
+            A parameter with the name 'a' already exists
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn function_declaration_reports_not_all_code_paths_return() {
+        let text = "
+            function [f](a: int): int {
+                if a > 0
+                    return 1
+            }
+            f(1)
+            ";
+        let diagnostics = "
+            Not all code paths return a value
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn global_return_reports_inconsistent_types() {
+        let text = "
+            var x = 1
+            if x > 0
+                return 1
+            if x > 1
+                return [true]
+            [return]
+            ";
+        let diagnostics = "
+            Cannot convert Boolean to Integer. An explicit conversion exists (are you missing a conversion?)
+            An expression of type Integer is expected
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn global_return_reports_unexpected_expression() {
+        let text = "
+            var x = 1
+            if x > 0
+                return
+            return [x]
+            ";
+        let diagnostics = "
+            An earlier 'return' ends the script without a value, so 'return' cannot be followed by an expression
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn return_statement_reports_cannot_convert() {
+        let text = "
+            function f(): int {
+                return [true]
+            }
+            f()
+            ";
+        let diagnostics = "
+            Cannot convert Boolean to Integer. An explicit conversion exists (are you missing a conversion?)
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn return_statement_reports_expression_in_function_without_value() {
+        let text = "
+            function f() {
+                return [1]
+            }
+            f()
+            ";
+        let diagnostics = "
+            Function 'f' doesn't return a value, so 'return' cannot be followed by an expression
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn return_statement_reports_missing_expression() {
+        let text = "
+            function f(): int {
+                [return]
+            }
+            f()
+            ";
+        let diagnostics = "
+            An expression of type Integer is expected
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn break_statement_reports_outside_of_loop() {
+        let text = "
+            [break]
+            ";
+        let diagnostics = "
+            The 'break' keyword can only be used inside of loops
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn continue_statement_reports_outside_of_loop() {
+        let text = "
+            function f() {
+                [continue]
+            }
+            while true f()
+            ";
+        let diagnostics = "
+            The 'continue' keyword can only be used inside of loops
+            ";
+        assert_has_diagnostics(text, diagnostics);
+    }
+
+    #[test]
+    fn division_reports_division_by_zero() {
+        let text = "
+            var x = 0
+            [10 / x]
+            ";
+        assert_has_runtime_error(
+            text,
+            RuntimeErrorKind::DivisionByZero,
+            "Attempted to divide by zero",
+        );
+    }
+
+    #[test]
+    fn call_reports_unbounded_recursion() {
+        let text = "
+            function f(n: int): int {
+                if n < 0 {
+                    return 0
+                }
+                return 1 + (n * (1 + (2 * (3 - [f(n + 1)]))))
+            }
+            f(0)
+            ";
+        assert_has_runtime_error(
+            text,
+            RuntimeErrorKind::StackOverflow,
+            "Calls are nested more than 1000 deep",
+        );
+    }
+
+    #[test]
+    fn division_reports_division_by_zero_inside_function() {
+        let text = "
+            function f(a: int): int {
+                return [1 / a]
+            }
+            f(0)
+            ";
+        assert_has_runtime_error(
+            text,
+            RuntimeErrorKind::DivisionByZero,
+            "Attempted to divide by zero",
+        );
+    }
+
+    const CHECKED: CompilationOptions = CompilationOptions {
+        checked_arithmetic: true,
+    };
+
+    #[test]
+    fn checked_arithmetic_reports_overflow() {
+        for text in &[
+            "var x = 2147483647 x [+] 1",
+            "var x = -2147483647 x [-] 2",
+            "var x = 65536 x [*] x",
+            "var x = -2147483647 - 1 x [/] -1",
+            "var x = -2147483647 - 1 var y = [-]x",
+            "var x = 2147483647 x [+=] 1",
+            "var x = 2147483647 x[++]",
+            "function f(x: int): int { return x [*] 2 } f(2147483647)",
+            "var b: int8 = 127 b [+] 1",
+            "var l: int64 = 9223372036854775807 l [+] 1",
+            "var u: uint = 0 u [-] 1",
+            "var x = 300 [int8(x)]",
+            "var f = 1e10 [int(f)]",
+        ] {
+            assert_has_runtime_error_with_options(
+                CHECKED,
+                text,
+                RuntimeErrorKind::Overflow,
+                "Arithmetic operation resulted in an overflow",
+            );
+        }
+    }
+
+    #[test]
+    fn checked_arithmetic_reports_constant_overflow() {
+        let text = "
+            var a = (2147483647 [+] 1) + 1
+            var b = [-](-2147483647 - 1)
+            var c = 1 + 65536 [*] 65536
+            var d = [int8(128)]
+            ";
+        let diagnostics = "
+            The operation overflows at compile time in checked mode
+            The operation overflows at compile time in checked mode
+            The operation overflows at compile time in checked mode
+            The operation overflows at compile time in checked mode
+            ";
+        assert_has_diagnostics_with_options(CHECKED, text, diagnostics);
+    }
+
+    #[test]
+    fn unchecked_arithmetic_folds_constant_overflow_by_wrapping() {
+        for (text, expected) in [
+            ("2147483647 + 1", MinskValue::Integer(-2147483648)),
+            ("-(-2147483647 - 1)", MinskValue::Integer(-2147483648)),
+            ("65536 * 65536", MinskValue::Integer(0)),
+            ("int8(128)", MinskValue::Int8(-128)),
+        ]
+        .iter()
+        {
+            try_evaluate(text, Some(expected.clone()));
+        }
+    }
+
+    #[test]
+    fn checked_arithmetic_evaluates_without_overflow() {
+        let text = "{ var x = 2147483646 x += 1 (-2147483647 - 1) / 2 + x - x * 1 }";
+        let result = Compilation::with_options(SyntaxTree::parse(text.to_string()), CHECKED)
+            .evaluate(&mut HashMap::new(), &mut TestHost::default());
+        asserting!("evaluated value")
+            .that(&result)
+            .is_ok()
+            .is_equal_to(Some(MinskValue::Integer(-1073741824)));
+    }
+
+    #[test]
+    fn built_in_functions_go_through_host() {
+        let mut host = TestHost {
+            input: VecDeque::from(vec!["Bob".to_string()]),
+            ..TestHost::default()
+        };
+        let text = "
+            print(\"Name?\")
+            let name = input()
+            print(\"Hi \" + name)
+            print(string(rnd(6)))
+            ";
+        let result = Compilation::new(SyntaxTree::parse(text.to_string()))
+            .evaluate(&mut HashMap::new(), &mut host);
+
+        asserting!("evaluated value")
+            .that(&result)
+            .is_ok()
+            .is_equal_to(Some(MinskValue::Null));
+        asserting!("output").that(&host.output).is_equal_to(vec![
+            "Name?".to_string(),
+            "Hi Bob".to_string(),
+            "5".to_string(),
+        ]);
+    }
+
+    #[test]
+    fn rnd_reports_invalid_argument() {
+        assert_has_runtime_error(
+            "[rnd(0)]",
+            RuntimeErrorKind::InvalidArgument,
+            "The maximum passed to rnd must be positive, but was 0",
+        );
+    }
+
+    #[test]
+    fn built_in_functions_can_be_shadowed() {
+        let text = "
+            function print(text: string) { }
+            print(\"a\")
+            ";
+        let mut host = TestHost::default();
+        let result = Compilation::new(SyntaxTree::parse(text.to_string()))
+            .evaluate(&mut HashMap::new(), &mut host);
+
+        asserting!("evaluated value").that(&result).is_ok();
+        asserting!("output").that(&host.output).is_empty();
+    }
+
+    #[test]
+    fn runtime_error_in_previous_submission_points_at_call() {
+        let mut variables = HashMap::new();
+        let mut previous = Compilation::new(SyntaxTree::parse(
+            "function f(a: int): int { return 1 / a }".to_string(),
+        ));
+        asserting!("declaring f")
+            .that(&previous.evaluate(&mut variables, &mut TestHost::default()))
+            .is_ok();
+
+        let annotated_text = AnnotatedText::parse("1 + [f(0)]");
+        let result = previous
+            .continue_with(SyntaxTree::parse(annotated_text.text.clone()))
+            .evaluate(&mut variables, &mut TestHost::default());
+        match result {
+            Err(EvaluationError::Runtime(error)) => asserting!("spans match")
+                .that(&vec![error.span])
+                .is_equal_to(annotated_text.spans),
+            r => panic!("expected a runtime error, got {:?}", r),
+        }
+    }
+
+    #[test]
+    fn struct_declared_again_reuses_its_type() {
+        let mut variables = HashMap::new();
+        let mut previous = Compilation::new(SyntaxTree::parse(
+            "struct Node { value: int, next: [Node] } var n = Node { value: 1, next: [] }"
+                .to_string(),
+        ));
+        asserting!("declaring Node")
+            .that(&previous.evaluate(&mut variables, &mut TestHost::default()))
+            .is_ok();
+
+        let result = previous
+            .continue_with(SyntaxTree::parse(
+                "struct Node { value: int, next: [Node] } n = Node { value: 2, next: [n] } n.next[0].value"
+                    .to_string(),
+            ))
+            .evaluate(&mut variables, &mut TestHost::default());
+        asserting!("evaluated value")
+            .that(&result)
+            .is_ok()
+            .is_equal_to(Some(MinskValue::Integer(1)));
+    }
+
+    #[test]
+    fn struct_declared_again_is_a_new_type_when_its_field_types_change() {
+        let mut variables = HashMap::new();
+        let mut compilation = Compilation::new(SyntaxTree::parse(
+            "struct Q { a: int } struct P { q: Q } var p = P { q: Q { a: 1 } }".to_string(),
+        ));
+        for text in ["struct Q { b: int }", "struct P { q: Q }"] {
+            asserting!("declaring a struct")
+                .that(&compilation.evaluate(&mut variables, &mut TestHost::default()))
+                .is_ok();
+            compilation = compilation.continue_with(SyntaxTree::parse(text.to_string()));
+        }
+        asserting!("declaring P again")
+            .that(&compilation.evaluate(&mut variables, &mut TestHost::default()))
+            .is_ok();
+
+        let annotated_text = AnnotatedText::parse("p = [P { q: Q { b: 1 } }]");
+        let result = compilation
+            .continue_with(SyntaxTree::parse(annotated_text.text.clone()))
+            .evaluate(&mut variables, &mut TestHost::default());
+        match result {
+            Err(EvaluationError::Diagnostics(diagnostics)) => asserting!("spans match")
+                .that(&diagnostics.iter().map(|d| d.span).collect::<Vec<_>>())
+                .is_equal_to(annotated_text.spans),
+            r => panic!("expected diagnostics, got {:?}", r),
+        }
+    }
+
+    #[test]
+    fn for_statement_does_not_leak_temporaries_into_globals() {
+        let mut variables = HashMap::new();
+        let result = Compilation::new(SyntaxTree::parse(
+            "var s = 2 for i = 1 to 10 step s { }".to_string(),
+        ))
+        .evaluate(&mut variables, &mut TestHost::default());
+        asserting!("evaluating the loop").that(&result).is_ok();
+
+        let mut names = variables.keys().map(|v| v.name()).collect::<Vec<_>>();
+        names.sort_unstable();
+        asserting!("globals after the loop")
+            .that(&names)
+            .is_equal_to(vec!["i", "s"]);
+    }
+
+    #[test]
+    fn float_prints_non_finite_values_as_divisions() {
+        for (text, expected) in [
+            ("1.0 / 0.0", "1.0 / 0.0"),
+            ("-1.0 / 0.0", "-1.0 / 0.0"),
+            ("0.0 / 0.0", "0.0 / 0.0"),
+        ]
+        .iter()
+        {
+            let value = Compilation::new(SyntaxTree::parse(text.to_string()))
+                .evaluate(&mut HashMap::new(), &mut TestHost::default())
+                .unwrap()
+                .unwrap();
+            asserting!("printed value")
+                .that(&value.to_string().as_str())
+                .is_equal_to(expected);
+            // and the output reads back as the same value
+            let reread = Compilation::new(SyntaxTree::parse(value.to_string()))
+                .evaluate(&mut HashMap::new(), &mut TestHost::default())
+                .unwrap()
+                .unwrap();
+            asserting!("reread value")
+                .that(&reread.to_string().as_str())
+                .is_equal_to(expected);
+        }
+    }
+
     #[test]
     fn block_statement_no_infinite_loops() {
         let text = "
@@ -505,6 +2136,15 @@ mod tests {
             ("false", MinskValue::Boolean(false)),
             ("!true", MinskValue::Boolean(false)),
             ("!false", MinskValue::Boolean(true)),
+            ("\"test\"", MinskValue::String("test".to_string())),
+            ("\"te\\\"st\"", MinskValue::String("te\"st".to_string())),
+            ("\"a\\tb\\n\"", MinskValue::String("a\tb\n".to_string())),
+            ("\"\\u{48}i\\\\\"", MinskValue::String("Hi\\".to_string())),
+            ("\"ab\" + \"cd\"", MinskValue::String("abcd".to_string())),
+            ("\"ab\" == \"ab\"", MinskValue::Boolean(true)),
+            ("\"ab\" == \"cd\"", MinskValue::Boolean(false)),
+            ("\"ab\" != \"ab\"", MinskValue::Boolean(false)),
+            ("\"ab\" != \"cd\"", MinskValue::Boolean(true)),
             ("{ var a = 0 (a = 10) * a }", MinskValue::Integer(100)),
             ("{ var a = 0 if a == 0 a = 10 a }", MinskValue::Integer(10)),
             ("{ var a = 0 if a != 0 a = 10 a }", MinskValue::Integer(0)),
@@ -517,7 +2157,222 @@ mod tests {
                 MinskValue::Integer(5),
             ),
             ("{ var i = 10 var result = 0 while i > 0 { result = result + i i = i - 1 } result }", MinskValue::Integer(55)),
-            ("{ var result = 0 for i = 1 to 10 { result = result + i } result }", MinskValue::Integer(55))
+            ("{ var result = 0 for i = 1 to 10 { result = result + i } result }", MinskValue::Integer(55)),
+            ("function add(a: int, b: int): int { return a + b } add(1, 2)", MinskValue::Integer(3)),
+            ("function greet(name: string): string { return \"Hi \" + name } greet(\"Bob\")", MinskValue::String("Hi Bob".to_string())),
+            ("function fib(n: int): int { if n < 2 return n return fib(n - 1) + fib(n - 2) } fib(10)", MinskValue::Integer(55)),
+            ("function f(n: int): int { var x = n if n > 0 f(n - 1) return x } f(3)", MinskValue::Integer(3)),
+            ("function f(): int { var x = 5 return x } { var x = 1 f() + x }", MinskValue::Integer(6)),
+            ("function f(): int { var x = 1 { var x = 2 } return x } f()", MinskValue::Integer(1)),
+            ("{ var x = 1 { var x = 2 } x }", MinskValue::Integer(1)),
+            ("function f(): int { while true { return 2 } return 1 } f()", MinskValue::Integer(2)),
+            ("function f(): int { for i = 1 to 10 { if i == 4 return i } return 0 } f()", MinskValue::Integer(4)),
+            ("function f(): int { return g() } function g(): int { return 7 } f()", MinskValue::Integer(7)),
+            ("var x = 1 x = x + 1 x", MinskValue::Integer(2)),
+            ("{ var i = 0 while true { i = i + 1 if i == 5 break } i }", MinskValue::Integer(5)),
+            ("{ var result = 0 do result = result + 1 while false result }", MinskValue::Integer(1)),
+            ("{ var i = 0 do { i = i + 1 } while i < 10 i }", MinskValue::Integer(10)),
+            ("{ var i = 0 var odd = 0 do { i = i + 1 if i / 2 * 2 == i continue if i > 7 break odd = odd + 1 } while true odd }", MinskValue::Integer(4)),
+            ("{ var result = 0 for i = 1 to 10 { if i / 2 * 2 == i continue result = result + i } result }", MinskValue::Integer(25)),
+            ("{ var result = 0 for i = 1 to 3 { for j = 1 to 10 { if j > i break result = result + 1 } } result }", MinskValue::Integer(6)),
+            ("{ var i = 0 var result = 0 while i < 5 { i = i + 1 if i == 2 continue result = result + i } result }", MinskValue::Integer(13)),
+            ("function f(): int { for i = 1 to 10 { if i == 3 break } return 7 } f()", MinskValue::Integer(7)),
+            ("{ var result = 0 for i = 1 to 3 { for j = 1 to 2 { result = result + 1 } } result }", MinskValue::Integer(6)),
+            ("{ var a = 0 if a == 0 { if a == 1 a = 5 else a = 10 } else a = 20 a }", MinskValue::Integer(10)),
+            ("{ var result = 0 for i = 3 to 1 { result = result + 1 } result }", MinskValue::Integer(0)),
+            ("{ var i = 0 var evens = 0 while i < 10 { if i / 2 * 2 == i evens = evens + 1 i = i + 1 } evens }", MinskValue::Integer(5)),
+            ("1 + /* two */ 2 // three", MinskValue::Integer(3)),
+            ("(-2147483647 - 1) / -1", MinskValue::Integer(i32::MIN)),
+            ("{ var x = 2147483647 x + 1 }", MinskValue::Integer(i32::MIN)),
+            ("2147483647 + 1", MinskValue::Integer(i32::MIN)),
+            ("-(-2147483647 - 1)", MinskValue::Integer(i32::MIN)),
+            ("var x = 4 // x = 5\nx", MinskValue::Integer(4)),
+            ("/* /* nested */ 1 */ 2", MinskValue::Integer(2)),
+            ("var a = 2 function f(): int { return a * 3 } f()", MinskValue::Integer(6)),
+            ("f() function f(): int { return 4 }", MinskValue::Integer(4)),
+            ("{ var a = 1 a += 2 a }", MinskValue::Integer(3)),
+            ("{ var a = 5 a -= 2 a }", MinskValue::Integer(3)),
+            ("{ var a = 3 a *= 4 a }", MinskValue::Integer(12)),
+            ("{ var a = 12 a /= 4 a }", MinskValue::Integer(3)),
+            ("{ var a = 1 (a += 2) * a }", MinskValue::Integer(9)),
+            ("{ var s = \"ab\" s += \"cd\" s }", MinskValue::String("abcd".to_string())),
+            ("{ var a = 1 a++ }", MinskValue::Integer(1)),
+            ("{ var a = 1 ++a }", MinskValue::Integer(2)),
+            ("{ var a = 1 a-- a }", MinskValue::Integer(0)),
+            ("{ var a = 1 --a }", MinskValue::Integer(0)),
+            ("{ var result = 0 for i = 1 to 10 result += i result }", MinskValue::Integer(55)),
+            ("{ var i = 0 while i < 10 i++ i }", MinskValue::Integer(10)),
+            ("7 % 3", MinskValue::Integer(1)),
+            ("-7 % 3", MinskValue::Integer(-1)),
+            ("(-2147483647 - 1) % -1", MinskValue::Integer(0)),
+            ("6 & 3", MinskValue::Integer(2)),
+            ("6 | 3", MinskValue::Integer(7)),
+            ("6 ^ 3", MinskValue::Integer(5)),
+            ("~1", MinskValue::Integer(-2)),
+            ("1 << 4", MinskValue::Integer(16)),
+            ("-16 >> 2", MinskValue::Integer(-4)),
+            ("1 << 33", MinskValue::Integer(2)),
+            ("true & false", MinskValue::Boolean(false)),
+            ("true | false", MinskValue::Boolean(true)),
+            ("true ^ true", MinskValue::Boolean(false)),
+            ("1 + 2 << 1", MinskValue::Integer(6)),
+            ("1 | 2 & 3", MinskValue::Integer(3)),
+            ("1 ^ 3 & 1", MinskValue::Integer(0)),
+            ("1 < 2 == 2 < 3", MinskValue::Boolean(true)),
+            ("2 + 7 % 3", MinskValue::Integer(3)),
+            ("true || false && false", MinskValue::Boolean(true)),
+            ("false && true || true", MinskValue::Boolean(true)),
+            ("{ var x: int = 4 x }", MinskValue::Integer(4)),
+            ("{ let ok: bool = 1 < 2 ok }", MinskValue::Boolean(true)),
+            ("function f(): string { return \"a\" } let s: string = f() s", MinskValue::String("a".to_string())),
+            ("1.5", MinskValue::Float(1.5)),
+            ("2.5e2", MinskValue::Float(250.0)),
+            ("1E-2", MinskValue::Float(0.01)),
+            ("-0.5 * 4.0", MinskValue::Float(-2.0)),
+            ("1 + 2.5", MinskValue::Float(3.5)),
+            ("2.5 + 1", MinskValue::Float(3.5)),
+            ("7 / 2.0", MinskValue::Float(3.5)),
+            ("7 / 2", MinskValue::Integer(3)),
+            ("5.5 % 2", MinskValue::Float(1.5)),
+            ("1 < 1.5", MinskValue::Boolean(true)),
+            ("2 == 2.0", MinskValue::Boolean(true)),
+            ("0.1 + 0.2 != 0.3", MinskValue::Boolean(true)),
+            ("1.0 / 0 > 1e308", MinskValue::Boolean(true)),
+            ("{ var f = 1.5 f += 1 f++ f }", MinskValue::Float(3.5)),
+            ("{ var f: float = 2 f }", MinskValue::Float(2.0)),
+            ("function half(x: float): float { return x / 2 } half(3)", MinskValue::Float(1.5)),
+            ("int(2.9) + int(-2.9)", MinskValue::Integer(0)),
+            ("float(\"1.25\")", MinskValue::Float(1.25)),
+            ("string(1.0) + string(0.5) + string(1e21)", MinskValue::String("1.00.51e21".to_string())),
+            ("bool(0)", MinskValue::Boolean(false)),
+            ("bool(-3)", MinskValue::Boolean(true)),
+            ("int(true)", MinskValue::Integer(1)),
+            ("int(false)", MinskValue::Integer(0)),
+            ("int(\"42\") + 1", MinskValue::Integer(43)),
+            ("bool(\"true\")", MinskValue::Boolean(true)),
+            ("string(12) + string(true)", MinskValue::String("12true".to_string())),
+            ("int(7)", MinskValue::Integer(7)),
+            ("{ var x = 0 var b: bool = bool(x + 1) b }", MinskValue::Boolean(true)),
+            ("function f(b: bool): int { return int(b) } f(bool(5))", MinskValue::Integer(1)),
+            ("{ var x = 0 false && (x = 1) == 1 x }", MinskValue::Integer(0)),
+            ("{ var x = 0 true || (x = 1) == 1 x }", MinskValue::Integer(0)),
+            ("{ var x = 0 true && (x = 1) == 1 x }", MinskValue::Integer(1)),
+            ("{ var x = 0 false || (x = 1) == 1 x }", MinskValue::Integer(1)),
+            ("{ var x = 0 x != 0 && 10 / x == 1 }", MinskValue::Boolean(false)),
+            ("var calls = 0 function f(): bool { calls += 1 return true } false && f() true || f() calls", MinskValue::Integer(0)),
+            ("var calls = 0 function f(): bool { calls += 1 return true } true && f() false || f() calls", MinskValue::Integer(2)),
+            ("3000000000", MinskValue::Int64(3000000000)),
+            ("{ var b: int8 = 127 b += 1 b }", MinskValue::Int8(-128)),
+            ("{ var b: int8 = 100 b + 1 }", MinskValue::Int8(101)),
+            ("{ var b: int8 = 100 1 + b }", MinskValue::Int8(101)),
+            ("{ var b: int8 = 1 b++ b }", MinskValue::Int8(2)),
+            ("{ var s: int16 = 1000 var b: int8 = 2 s * b }", MinskValue::Int16(2000)),
+            ("function f(x: int16): int16 { return x * 2 } f(300)", MinskValue::Int16(600)),
+            ("{ var u: uint = 3000000000 u }", MinskValue::UInt(3000000000)),
+            ("{ var u: uint = 0 u - 1 }", MinskValue::UInt(4294967295)),
+            ("{ var u: uint = 1 var n = -u n }", MinskValue::Int64(-1)),
+            ("{ var x = 1 var l: int64 = x l + x }", MinskValue::Int64(2)),
+            ("{ var l: int64 = 1 l << 40 }", MinskValue::Int64(1 << 40)),
+            ("{ var b: int8 = -128 b >> 1 }", MinskValue::Int8(-64)),
+            ("{ var u: uint = 4294967295 u >> 31 }", MinskValue::UInt(1)),
+            ("{ var b: int8 = 1 ~b }", MinskValue::Int8(-2)),
+            ("{ var u: uint = 1 ~u }", MinskValue::UInt(4294967294)),
+            ("int64(5) == 5", MinskValue::Boolean(true)),
+            ("int8(300)", MinskValue::Int8(44)),
+            ("int8(-1.5)", MinskValue::Int8(-1)),
+            ("uint(-1)", MinskValue::UInt(4294967295)),
+            ("int(1e10)", MinskValue::Integer(2147483647)),
+            ("int16(\"-300\")", MinskValue::Int16(-300)),
+            ("float(int64(1) << 53)", MinskValue::Float(9007199254740992.0)),
+            ("string(int8(-5)) + string(bool(int8(3)))", MinskValue::String("-5true".to_string())),
+            ("[1, 2, 3][1]", MinskValue::Integer(2)),
+            ("{ var a = [1, 2, 3] a[0] = 5 a[0] + a[2] }", MinskValue::Integer(8)),
+            ("{ var a = [1, 2] var b = a b[0] = 7 a[0] }", MinskValue::Integer(7)),
+            ("{ var a = [1, 2] a[1] += 5 a[1] }", MinskValue::Integer(7)),
+            ("{ var a = [[1], [2, 3]] a[1][1] }", MinskValue::Integer(3)),
+            ("[1, 2.5][0]", MinskValue::Float(1.0)),
+            ("len([1, 2, 3])", MinskValue::Integer(3)),
+            ("{ var a: [int] = [] len(a) }", MinskValue::Integer(0)),
+            ("function sum(a: [int]): int { var s = 0 for i = 0 to len(a) - 1 { s += a[i] } return s } sum([1, 2, 3])", MinskValue::Integer(6)),
+            ("struct Point { x: int, y: int } var p = Point { x: 1, y: 2 } p.x * 10 + p.y", MinskValue::Integer(12)),
+            ("return 5", MinskValue::Integer(5)),
+            ("true ? 1 : 2", MinskValue::Integer(1)),
+            ("{ var b = false b ? 1 : 2 }", MinskValue::Integer(2)),
+            ("{ var x = 5 x > 3 ? \"big\" : \"small\" }", MinskValue::String("big".to_string())),
+            ("{ var a = 2 a == 1 ? 10 : a == 2 ? 20 : 30 }", MinskValue::Integer(20)),
+            ("{ var a = 3 a == 1 ? 10 : a == 2 ? 20 : 30 }", MinskValue::Integer(30)),
+            ("{ var b = true b ? 1 : 2.5 }", MinskValue::Float(1.0)),
+            ("{ var b = true 1 + (b ? 1 : 2) * 3 }", MinskValue::Integer(4)),
+            ("{ var b = false b || true ? 1 : 2 }", MinskValue::Integer(1)),
+            ("{ var z = 0 var b = false b ? 1 / z : 5 }", MinskValue::Integer(5)),
+            ("var calls = 0 function f(): int { calls += 1 return 1 } var b = true var x = b ? 2 : f() calls", MinskValue::Integer(0)),
+            ("'a'", MinskValue::Char('a')),
+            ("'\\''", MinskValue::Char('\'')),
+            ("'\\u{1F600}'", MinskValue::Char('\u{1F600}')),
+            ("'a' < 'b'", MinskValue::Boolean(true)),
+            ("'b' >= 'c'", MinskValue::Boolean(false)),
+            ("'x' == 'x'", MinskValue::Boolean(true)),
+            ("int('A')", MinskValue::Integer(65)),
+            ("char(97)", MinskValue::Char('a')),
+            ("char(int('a') + 2)", MinskValue::Char('c')),
+            ("string('x') + \"y\"", MinskValue::String("xy".to_string())),
+            ("char(\"z\")", MinskValue::Char('z')),
+            ("{ var s = \"\" for c = 'a' to 'e' { s = s + string(c) } s }", MinskValue::String("abcde".to_string())),
+            ("{ var n = 0 for c = 'x' to 'a' n += 1 n }", MinskValue::Integer(0)),
+            ("{ var n = 0 for c = 'a' to 'z' { if c == 'd' continue n += 1 } n }", MinskValue::Integer(25)),
+            ("{ var n = 0 for i = 2147483646 to 2147483647 n += 1 n }", MinskValue::Integer(2)),
+            ("{ var s = \"\" for i = 10 to 0 step -2 s = s + string(i) + \",\" s }", MinskValue::String("10,8,6,4,2,0,".to_string())),
+            ("{ var s = 0 for i = 1 to 10 step 3 s += i s }", MinskValue::Integer(22)),
+            ("{ var n = 0 for i = 1 to 10 step -1 n += 1 n }", MinskValue::Integer(0)),
+            ("{ var n = 0 for i = 5 to 5 step -3 n += 1 n }", MinskValue::Integer(1)),
+            ("{ var s = \"\" for c = 'a' to 'g' step 3 s = s + string(c) s }", MinskValue::String("adg".to_string())),
+            ("{ var s = \"\" for c = 'e' to 'a' step -2 s = s + string(c) s }", MinskValue::String("eca".to_string())),
+            ("function f(): int { let upperBound1 = 100 for i = 1 to 3 { } return upperBound1 } f()", MinskValue::Integer(100)),
+            ("function g(): int { let i = 100 for i = 1 to 3 { } return i } g()", MinskValue::Integer(100)),
+            ("{ let c = 'z' for c = 'a' to 'c' { } c }", MinskValue::Char('z')),
+            ("{ var n = 0 for c = '\\u{D7FE}' to '\\u{E000}' n += int(c) - 0xD7FE n }", MinskValue::Integer(1 + 0x802)),
+            ("{ var n = 0 for c = '\\u{E001}' to '\\u{D7FF}' step -1 n += 1 n }", MinskValue::Integer(3)),
+            ("{ var s = \"\" for c = '\\u{D7FE}' to '\\u{E001}' step 2 s = s + string(int(c)) + \",\" s }", MinskValue::String("55294,57344,".to_string())),
+            ("{ var n = 0 for c = '\\u{D7FF}' to '\\u{E000}' { if c == '\\u{E000}' continue n += 1 } n }", MinskValue::Integer(1)),
+            ("{ var d = -3 var s = 0 for i = 9 to 0 step d s += i s }", MinskValue::Integer(18)),
+            ("{ var d = 4 var n = 0 for i = 0 to 10 step d { if i == 4 continue n += i } n }", MinskValue::Integer(8)),
+            ("{ var n = 0 for i = 2147483640 to 2147483647 step 5 n += 1 n }", MinskValue::Integer(2)),
+            ("0x1F", MinskValue::Integer(31)),
+            ("0XfF + 0b1010 + 0o17", MinskValue::Integer(280)),
+            ("0b_1000_0000", MinskValue::Integer(128)),
+            ("1_000_000", MinskValue::Integer(1000000)),
+            ("1_000.5e1_0", MinskValue::Float(1000.5e10)),
+            ("if false 1else 2", MinskValue::Integer(2)),
+            ("0x7FFF_FFFF", MinskValue::Integer(2147483647)),
+            ("0x8000_0000", MinskValue::Int64(2147483648)),
+            ("0x7FFF_FFFF_FFFF_FFFF", MinskValue::Int64(i64::MAX)),
+            ("-0x10", MinskValue::Integer(-16)),
+            ("{ var n = 0 for i = -2147483647 to -2147483648 step -1 n += 1 n }", MinskValue::Integer(2)),
+            ("{ var d = 2147483647 var n = 0 for i = -2147483648 to 2147483647 step d n += 1 n }", MinskValue::Integer(3)),
+            ("var x = 1 return x + 1 x = 10", MinskValue::Integer(2)),
+            ("var x = 0 if x == 0 return \"zero\" return \"other\"", MinskValue::String("zero".to_string())),
+            ("for i = 1 to 10 { while true { { if i == 3 return i * 2 } break } } return 0", MinskValue::Integer(6)),
+            ("function f(): int { return 1 } var x = f() return x + f()", MinskValue::Integer(2)),
+            ("var x = 3 if x > 1 { x = 7 return } x = 9 x", MinskValue::Null),
+            ("struct Point { x: int, y: int } var p = Point { y: 2, x: 1 } p.x = 5 p.x", MinskValue::Integer(5)),
+            ("struct Point { x: int, y: int } var p = Point { x: 1, y: 2 } p.y += 3 p.y", MinskValue::Integer(5)),
+            ("struct Point { x: int, y: int } var p = Point { x: 1, y: 2 } var q = p q.x = 9 p.x", MinskValue::Integer(9)),
+            ("struct P { f: float, s: string } P { f: 1, s: \"a\" }.f", MinskValue::Float(1.0)),
+            ("struct Box { items: [int] } var b = Box { items: [1, 2] } b.items[1] = 4 b.items[1]", MinskValue::Integer(4)),
+            ("struct Point { x: int, y: int } struct Line { a: Point, b: Point } var l = Line { a: Point { x: 1, y: 2 }, b: Point { x: 3, y: 4 } } l.b.x", MinskValue::Integer(3)),
+            ("struct Point { x: int, y: int } function sum(p: Point): int { return p.x + p.y } sum(Point { x: 3, y: 4 })", MinskValue::Integer(7)),
+            ("struct Line { a: Point, b: Point } struct Point { x: int, y: int } Line { a: Point { x: 1, y: 2 }, b: Point { x: 3, y: 4 } }.a.y", MinskValue::Integer(2)),
+            ("struct Node { value: int, children: [Node] } var leaf: [Node] = [] var n = Node { value: 1, children: [Node { value: 2, children: leaf }] } n.children[0].value", MinskValue::Integer(2)),
+            ("{ var x = true var a = 1 if x { } a }", MinskValue::Integer(1)),
+            ("{ var a = 0 while a > 3 { } a }", MinskValue::Integer(0)),
+            ("{ var a = 1; var b = 2; a = 1; -b }", MinskValue::Integer(-2)),
+            ("{ var a = 1 var b = 2 a = 1 -b }", MinskValue::Integer(-1)),
+            ("{ var a = 5; a++; a }", MinskValue::Integer(6)),
+            ("{ var a = [1]; var b = 2; b; [3][0] }", MinskValue::Integer(3)),
+            ("{ var x = 3; do x = x - 1; while x > 0; x }", MinskValue::Integer(0)),
+            ("{ var n = 0; while true { n += 1; if n == 3 break; continue; } n }", MinskValue::Integer(3)),
+            ("function f(): int { return 4; } f();", MinskValue::Integer(4)),
+            ("var x = 1; return; x", MinskValue::Null),
         ]
         .iter()
         {
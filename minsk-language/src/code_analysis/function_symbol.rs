@@ -0,0 +1,31 @@
+use super::{minsk_type::MinskType, parameter_symbol::ParameterSymbol};
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct FunctionSymbol {
+    name: String,
+    parameters: Vec<ParameterSymbol>,
+    ty: MinskType,
+}
+
+impl FunctionSymbol {
+    pub(crate) fn new(name: String, parameters: Vec<ParameterSymbol>, ty: MinskType) -> Self {
+        Self {
+            name,
+            parameters,
+            ty,
+        }
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn parameters(&self) -> &[ParameterSymbol] {
+        &self.parameters
+    }
+
+    /// The return type; `Null` for functions that don't return a value.
+    pub(crate) fn ty(&self) -> MinskType {
+        self.ty
+    }
+}
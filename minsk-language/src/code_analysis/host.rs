@@ -0,0 +1,10 @@
+/// The environment a program runs in; the built-in functions go through it rather than
+/// touching the process's streams directly. Programs run on a thread of their own, hence `Send`.
+pub trait Host: Send {
+    /// Called by `print(text)`.
+    fn print(&mut self, text: &str);
+    /// Called by `input()`; returns the next line without its line break.
+    fn input(&mut self) -> String;
+    /// Called by `rnd(max)`; returns a number in `0..max`. `max` is always positive.
+    fn random(&mut self, max: i32) -> i32;
+}
@@ -0,0 +1,461 @@
+use crate::code_analysis::{
+    binding::{
+        bound_assignment_expression::BoundAssignmentExpression,
+        bound_binary_expression::BoundBinaryExpression, bound_binary_operator::BoundBinaryOperator,
+        bound_block_statement::BoundBlockStatement,
+        bound_conditional_expression::BoundConditionalExpression,
+        bound_conditional_goto_statement::BoundConditionalGotoStatement,
+        bound_conversion_expression::BoundConversionExpression,
+        bound_do_while_statement::BoundDoWhileStatement, bound_expression::BoundExpression,
+        bound_expression_statement::BoundExpressionStatement,
+        bound_for_statement::BoundForStatement, bound_goto_statement::BoundGotoStatement,
+        bound_if_statement::BoundIfStatement, bound_label::BoundLabel,
+        bound_label_statement::BoundLabelStatement,
+        bound_literal_expression::BoundLiteralExpression, bound_statement::BoundStatement,
+        bound_variable_declaration::BoundVariableDeclaration,
+        bound_variable_expression::BoundVariableExpression,
+        bound_while_statement::BoundWhileStatement,
+    },
+    minsk_type::MinskType,
+    minsk_value::MinskValue,
+    syntax::syntax_kind::SyntaxKind,
+    text::text_span::TextSpan,
+    variable_symbol::{VariableKind, VariableSymbol},
+};
+
+/// Rewrites `if`, `while` and `for` statements into labels and gotos
+/// so that the evaluator only has to deal with a flat list of statements.
+pub(crate) struct Lowerer {
+    label_count: usize,
+    variable_count: usize,
+}
+
+impl Lowerer {
+    pub(crate) fn lower(statement: &BoundStatement) -> BoundBlockStatement {
+        let mut lowerer = Self {
+            label_count: 0,
+            variable_count: 0,
+        };
+        let result = lowerer.rewrite_statement(statement);
+        let mut statements = vec![];
+        Self::flatten(&result, &mut statements);
+        BoundBlockStatement::new(statements)
+    }
+
+    fn flatten(statement: &BoundStatement, statements: &mut Vec<BoundStatement>) {
+        match statement {
+            BoundStatement::Block(b) => {
+                for s in b.statements() {
+                    Self::flatten(s, statements);
+                }
+            }
+            s => statements.push(s.clone()),
+        }
+    }
+
+    fn generate_label(&mut self) -> BoundLabel {
+        self.label_count += 1;
+        BoundLabel::new(format!("Label{}", self.label_count))
+    }
+
+    fn rewrite_statement(&mut self, statement: &BoundStatement) -> BoundStatement {
+        match statement {
+            BoundStatement::Block(b) => BoundStatement::Block(BoundBlockStatement::new(
+                b.statements()
+                    .iter()
+                    .map(|s| self.rewrite_statement(s))
+                    .collect(),
+            )),
+            BoundStatement::DoWhile(d) => self.rewrite_do_while_statement(d),
+            BoundStatement::For(f) => self.rewrite_for_statement(f),
+            BoundStatement::If(i) => self.rewrite_if_statement(i),
+            BoundStatement::While(w) => self.rewrite_while_statement(w),
+            s => s.clone(),
+        }
+    }
+
+    fn rewrite_if_statement(&mut self, i: &BoundIfStatement) -> BoundStatement {
+        let then_statement = self.rewrite_statement(i.then_statement());
+        let statements = match i.else_statement() {
+            // gotoFalse <condition> end
+            // <then>
+            // end:
+            None => {
+                let end_label = self.generate_label();
+                vec![
+                    BoundStatement::ConditionalGoto(BoundConditionalGotoStatement::new(
+                        end_label.clone(),
+                        i.condition().clone(),
+                        false,
+                    )),
+                    then_statement,
+                    BoundStatement::Label(BoundLabelStatement::new(end_label)),
+                ]
+            }
+            // gotoFalse <condition> else
+            // <then>
+            // goto end
+            // else:
+            // <else>
+            // end:
+            Some(else_statement) => {
+                let else_statement = self.rewrite_statement(else_statement);
+                let else_label = self.generate_label();
+                let end_label = self.generate_label();
+                vec![
+                    BoundStatement::ConditionalGoto(BoundConditionalGotoStatement::new(
+                        else_label.clone(),
+                        i.condition().clone(),
+                        false,
+                    )),
+                    then_statement,
+                    BoundStatement::Goto(BoundGotoStatement::new(end_label.clone())),
+                    BoundStatement::Label(BoundLabelStatement::new(else_label)),
+                    else_statement,
+                    BoundStatement::Label(BoundLabelStatement::new(end_label)),
+                ]
+            }
+        };
+        BoundStatement::Block(BoundBlockStatement::new(statements))
+    }
+
+    // goto continue
+    // body:
+    // <body>
+    // continue:
+    // gotoTrue <condition> body
+    // break:
+    fn rewrite_while_statement(&mut self, w: &BoundWhileStatement) -> BoundStatement {
+        let body = self.rewrite_statement(w.body());
+        let body_label = self.generate_label();
+        BoundStatement::Block(BoundBlockStatement::new(vec![
+            BoundStatement::Goto(BoundGotoStatement::new(w.continue_label().clone())),
+            BoundStatement::Label(BoundLabelStatement::new(body_label.clone())),
+            body,
+            BoundStatement::Label(BoundLabelStatement::new(w.continue_label().clone())),
+            BoundStatement::ConditionalGoto(BoundConditionalGotoStatement::new(
+                body_label,
+                w.condition().clone(),
+                true,
+            )),
+            BoundStatement::Label(BoundLabelStatement::new(w.break_label().clone())),
+        ]))
+    }
+
+    // body:
+    // <body>
+    // continue:
+    // gotoTrue <condition> body
+    // break:
+    fn rewrite_do_while_statement(&mut self, d: &BoundDoWhileStatement) -> BoundStatement {
+        let body = self.rewrite_statement(d.body());
+        let body_label = self.generate_label();
+        BoundStatement::Block(BoundBlockStatement::new(vec![
+            BoundStatement::Label(BoundLabelStatement::new(body_label.clone())),
+            body,
+            BoundStatement::Label(BoundLabelStatement::new(d.continue_label().clone())),
+            BoundStatement::ConditionalGoto(BoundConditionalGotoStatement::new(
+                body_label,
+                d.condition().clone(),
+                true,
+            )),
+            BoundStatement::Label(BoundLabelStatement::new(d.break_label().clone())),
+        ]))
+    }
+
+    // {
+    //     var <counter> = <lower>
+    //     let upperBound = <upper>
+    //     let step = <step>
+    //     while step > 0 ? <counter> <= upperBound : <counter> >= upperBound {
+    //         <body>
+    //         continue:
+    //         let distance = int64(upperBound) - int64(<counter>)
+    //         if step > 0 ? distance < step : distance > step break
+    //         <counter> = <counter> + step
+    //     }
+    // }
+    //
+    // Leaving before an increment that would pass the upper bound means a loop up to the
+    // largest or smallest int or char never has to step past it. Without a step the loop
+    // counts up by one, so it leaves once `<counter> == upperBound`, and a constant step
+    // settles the direction up front.
+    //
+    // An int loop counts with its own variable. A char loop counts through the code points
+    // in an int instead, so that it can step across the surrogates, and its body becomes
+    //
+    //     if <counter> >= 0xD800 && <counter> <= 0xDFFF goto continue
+    //     let <variable> = char(<counter>)
+    //     <body>
+    fn rewrite_for_statement(&mut self, f: &BoundForStatement) -> BoundStatement {
+        self.variable_count += 1;
+        let is_char = f.variable().ty() == MinskType::Char;
+        // symbols compare by identity, so the names only make lowered code easier to read.
+        // the temporaries are local even at global scope, so they don't outlive the loop
+        let counter = if is_char {
+            VariableSymbol::new(
+                format!("$index{}", self.variable_count),
+                false,
+                MinskType::Integer,
+                VariableKind::Local,
+            )
+        } else {
+            f.variable().clone()
+        };
+        let upper_bound = VariableSymbol::new(
+            format!("$upperBound{}", self.variable_count),
+            true,
+            MinskType::Integer,
+            VariableKind::Local,
+        );
+        let counter_expression = Self::variable_expression(counter.clone());
+        let upper_bound_expression = Self::variable_expression(upper_bound.clone());
+        let as_counter = |bound: &BoundExpression| {
+            if is_char {
+                Self::conversion(bound.clone(), MinskType::Integer, f.span())
+            } else {
+                bound.clone()
+            }
+        };
+
+        let mut statements = vec![
+            BoundStatement::VariableDeclaration(BoundVariableDeclaration::new(
+                counter.clone(),
+                as_counter(f.lower_bound()),
+            )),
+            BoundStatement::VariableDeclaration(BoundVariableDeclaration::new(
+                upper_bound,
+                as_counter(f.upper_bound()),
+            )),
+        ];
+        let (step, ascending) = match f.step() {
+            None => (Self::integer_literal(1), Some(true)),
+            Some(BoundExpression::Literal(l)) => (
+                BoundExpression::Literal(l.clone()),
+                Some(l.value.as_integer().unwrap() > 0),
+            ),
+            Some(s) => {
+                let step = VariableSymbol::new(
+                    format!("$step{}", self.variable_count),
+                    true,
+                    MinskType::Integer,
+                    VariableKind::Local,
+                );
+                statements.push(BoundStatement::VariableDeclaration(
+                    BoundVariableDeclaration::new(step.clone(), s.clone()),
+                ));
+                (Self::variable_expression(step), None)
+            }
+        };
+
+        let condition = Self::directed(
+            ascending,
+            &step,
+            Self::binary_expression(
+                counter_expression.clone(),
+                SyntaxKind::LessEquals,
+                upper_bound_expression.clone(),
+                MinskType::Integer,
+            ),
+            Self::binary_expression(
+                counter_expression.clone(),
+                SyntaxKind::GreaterEquals,
+                upper_bound_expression.clone(),
+                MinskType::Integer,
+            ),
+        );
+        let at_upper_bound = if f.step().is_none() {
+            Self::binary_expression(
+                counter_expression.clone(),
+                SyntaxKind::EqualsEquals,
+                upper_bound_expression,
+                MinskType::Integer,
+            )
+        } else {
+            // a wider type, so that the distance can't overflow
+            let distance = Self::binary_expression(
+                Self::conversion(upper_bound_expression, MinskType::Int64, f.span()),
+                SyntaxKind::Minus,
+                Self::conversion(counter_expression.clone(), MinskType::Int64, f.span()),
+                MinskType::Int64,
+            );
+            let wide_step = Self::conversion(step.clone(), MinskType::Int64, f.span());
+            Self::directed(
+                ascending,
+                &step,
+                Self::binary_expression(
+                    distance.clone(),
+                    SyntaxKind::Less,
+                    wide_step.clone(),
+                    MinskType::Int64,
+                ),
+                Self::binary_expression(distance, SyntaxKind::Greater, wide_step, MinskType::Int64),
+            )
+        };
+        let at_upper_bound = BoundStatement::ConditionalGoto(BoundConditionalGotoStatement::new(
+            f.break_label().clone(),
+            at_upper_bound,
+            true,
+        ));
+        let increment = BoundStatement::Expression(BoundExpressionStatement::new(
+            BoundExpression::Assignment(BoundAssignmentExpression {
+                variable: counter,
+                expression: Box::new(Self::binary_expression(
+                    counter_expression.clone(),
+                    SyntaxKind::Plus,
+                    step,
+                    MinskType::Integer,
+                )),
+            }),
+        ));
+
+        let mut body = vec![];
+        if is_char {
+            let is_surrogate = Self::binary_expression(
+                Self::binary_expression(
+                    counter_expression.clone(),
+                    SyntaxKind::GreaterEquals,
+                    Self::integer_literal(0xD800),
+                    MinskType::Integer,
+                ),
+                SyntaxKind::AmpersandAmpersand,
+                Self::binary_expression(
+                    counter_expression.clone(),
+                    SyntaxKind::LessEquals,
+                    Self::integer_literal(0xDFFF),
+                    MinskType::Integer,
+                ),
+                MinskType::Boolean,
+            );
+            body.push(BoundStatement::ConditionalGoto(
+                BoundConditionalGotoStatement::new(f.continue_label().clone(), is_surrogate, true),
+            ));
+            body.push(BoundStatement::VariableDeclaration(
+                BoundVariableDeclaration::new(
+                    f.variable().clone(),
+                    Self::conversion(counter_expression, MinskType::Char, f.span()),
+                ),
+            ));
+        }
+        body.push(f.body().clone());
+        body.push(BoundStatement::Label(BoundLabelStatement::new(
+            f.continue_label().clone(),
+        )));
+        body.push(at_upper_bound);
+        body.push(increment);
+
+        // `continue` has to run the increment, so the while loop gets a continue label of its own
+        statements.push(BoundStatement::While(BoundWhileStatement::new(
+            condition,
+            Box::new(BoundStatement::Block(BoundBlockStatement::new(body))),
+            f.break_label().clone(),
+            self.generate_label(),
+        )));
+
+        let result = BoundStatement::Block(BoundBlockStatement::new(statements));
+        self.rewrite_statement(&result)
+    }
+
+    /// Picks `ascending` or `descending` by the sign of the step, at runtime if need be.
+    fn directed(
+        ascending: Option<bool>,
+        step: &BoundExpression,
+        when_ascending: BoundExpression,
+        when_descending: BoundExpression,
+    ) -> BoundExpression {
+        match ascending {
+            Some(true) => when_ascending,
+            Some(false) => when_descending,
+            None => BoundExpression::Conditional(BoundConditionalExpression {
+                condition: Box::new(Self::binary_expression(
+                    step.clone(),
+                    SyntaxKind::Greater,
+                    Self::integer_literal(0),
+                    MinskType::Integer,
+                )),
+                when_true: Box::new(when_ascending),
+                when_false: Box::new(when_descending),
+            }),
+        }
+    }
+
+    fn integer_literal(value: i32) -> BoundExpression {
+        BoundExpression::Literal(BoundLiteralExpression {
+            value: MinskValue::Integer(value),
+        })
+    }
+
+    fn variable_expression(variable: VariableSymbol) -> BoundExpression {
+        BoundExpression::Variable(BoundVariableExpression {
+            variable,
+            span: TextSpan { start: 0, end: 0 },
+        })
+    }
+
+    fn conversion(expression: BoundExpression, ty: MinskType, span: TextSpan) -> BoundExpression {
+        BoundExpression::Conversion(BoundConversionExpression {
+            ty,
+            expression: Box::new(expression),
+            span,
+        })
+    }
+
+    fn binary_expression(
+        left: BoundExpression,
+        operator: SyntaxKind,
+        right: BoundExpression,
+        ty: MinskType,
+    ) -> BoundExpression {
+        BoundExpression::Binary(BoundBinaryExpression {
+            left: Box::new(left),
+            op: BoundBinaryOperator::bind(operator, ty, ty).unwrap(),
+            right: Box::new(right),
+            // synthesized, so there is no source text to point at
+            span: TextSpan { start: 0, end: 0 },
+            operator_span: TextSpan { start: 0, end: 0 },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::code_analysis::{compilation::Compilation, syntax::syntax_tree::SyntaxTree};
+
+    use super::*;
+    use spectral::prelude::*;
+
+    #[test]
+    fn lowers_to_flat_statements() {
+        let text = "
+            var x = 0
+            do x = x - 1 while x > 0
+            for i = 1 to 10 {
+                if i == 5 {
+                    x = x + 1
+                } else {
+                    while x < 3 x = x + 1
+                }
+            }
+        ";
+        let mut compilation = Compilation::new(SyntaxTree::parse(text.to_string()));
+        let lowered = Lowerer::lower(compilation.global_scope().statement());
+
+        let structured = lowered
+            .statements()
+            .iter()
+            .filter(|s| {
+                matches!(
+                    s,
+                    BoundStatement::Block(_)
+                        | BoundStatement::DoWhile(_)
+                        | BoundStatement::For(_)
+                        | BoundStatement::If(_)
+                        | BoundStatement::While(_)
+                )
+            })
+            .count();
+        asserting!("structured statements left after lowering")
+            .that(&structured)
+            .is_equal_to(0);
+    }
+}
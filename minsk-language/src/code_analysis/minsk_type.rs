@@ -1,14 +1,76 @@
-use std::fmt::Display;
+use std::{collections::HashMap, fmt::Display, sync::OnceLock};
+
+use parking_lot::Mutex;
+
+use super::struct_symbol::StructSymbol;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum MinskType {
+    Int8,
+    Int16,
     Integer,
+    UInt,
+    Int64,
+    Float,
     Boolean,
+    Char,
+    String,
     Null,
+    /// Interned by [`MinskType::array`], so that types stay `Copy`.
+    Array(&'static MinskType),
+    /// Declared by [`StructSymbol::declare`].
+    Struct(&'static StructSymbol),
+}
+
+impl MinskType {
+    /// Ordered from narrowest to widest, which is the order overloads are preferred in.
+    pub(crate) const INTEGRAL: [MinskType; 5] = [
+        MinskType::Int8,
+        MinskType::Int16,
+        MinskType::Integer,
+        MinskType::UInt,
+        MinskType::Int64,
+    ];
+
+    pub(crate) fn array(element_type: MinskType) -> MinskType {
+        static ARRAY_TYPES: OnceLock<Mutex<HashMap<MinskType, &'static MinskType>>> =
+            OnceLock::new();
+        let mut array_types = ARRAY_TYPES.get_or_init(Default::default).lock();
+        MinskType::Array(
+            array_types
+                .entry(element_type)
+                .or_insert_with(|| Box::leak(Box::new(element_type))),
+        )
+    }
+
+    pub(crate) fn element_type(self) -> Option<MinskType> {
+        match self {
+            MinskType::Array(element_type) => Some(*element_type),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn is_integral(self) -> bool {
+        Self::INTEGRAL.contains(&self)
+    }
+
+    pub(crate) fn bits(self) -> u32 {
+        match self {
+            MinskType::Int8 => 8,
+            MinskType::Int16 => 16,
+            MinskType::Integer | MinskType::UInt => 32,
+            MinskType::Int64 => 64,
+            _ => unreachable!("{} is not an integral type", self),
+        }
+    }
 }
 
 impl Display for MinskType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            MinskType::Array(element_type) => write!(f, "[{}]", element_type),
+            MinskType::Struct(symbol) => write!(f, "{}", symbol.name()),
+            _ => write!(f, "{:?}", self),
+        }
     }
 }
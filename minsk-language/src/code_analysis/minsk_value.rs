@@ -1,21 +1,100 @@
-use std::fmt::Display;
+use std::{convert::TryFrom, fmt::Display, sync::Arc};
 
-#[derive(Debug, Clone, Eq)]
+use parking_lot::RwLock;
+
+use super::minsk_type::MinskType;
+
+pub(crate) type ArrayElements = Arc<RwLock<Vec<MinskValue>>>;
+pub(crate) type StructFields = Arc<RwLock<Vec<MinskValue>>>;
+
+#[derive(Debug, Clone)]
 pub enum MinskValue {
+    Int8(i8),
+    Int16(i16),
     Integer(i32),
+    UInt(u32),
+    Int64(i64),
+    Float(f64),
     Boolean(bool),
+    Char(char),
+    String(String),
     Null,
+    /// The array's type and its elements, which are shared by every copy of the value.
+    Array(MinskType, ArrayElements),
+    /// Like arrays, the fields are shared by every copy of the value.
+    Struct(MinskType, StructFields),
 }
 
 impl MinskValue {
+    /// Builds a value of an integral type, or `None` if it is out of that type's range.
+    pub(crate) fn integral(ty: MinskType, value: i128) -> Option<MinskValue> {
+        Some(match ty {
+            MinskType::Int8 => MinskValue::Int8(i8::try_from(value).ok()?),
+            MinskType::Int16 => MinskValue::Int16(i16::try_from(value).ok()?),
+            MinskType::Integer => MinskValue::Integer(i32::try_from(value).ok()?),
+            MinskType::UInt => MinskValue::UInt(u32::try_from(value).ok()?),
+            MinskType::Int64 => MinskValue::Int64(i64::try_from(value).ok()?),
+            _ => return None,
+        })
+    }
+
+    /// Like [`MinskValue::integral`], but truncates to the type's width instead of failing.
+    pub(crate) fn wrapping_integral(ty: MinskType, value: i128) -> MinskValue {
+        match ty {
+            MinskType::Int8 => MinskValue::Int8(value as i8),
+            MinskType::Int16 => MinskValue::Int16(value as i16),
+            MinskType::Integer => MinskValue::Integer(value as i32),
+            MinskType::UInt => MinskValue::UInt(value as u32),
+            MinskType::Int64 => MinskValue::Int64(value as i64),
+            _ => unreachable!("{} is not an integral type", ty),
+        }
+    }
+
+    /// Like [`MinskValue::integral`], but clamps to the type's range instead of failing.
+    pub(crate) fn saturating_integral(ty: MinskType, value: i128) -> MinskValue {
+        let (min, max) = match ty {
+            MinskType::Int8 => (i8::MIN as i128, i8::MAX as i128),
+            MinskType::Int16 => (i16::MIN as i128, i16::MAX as i128),
+            MinskType::Integer => (i32::MIN as i128, i32::MAX as i128),
+            MinskType::UInt => (u32::MIN as i128, u32::MAX as i128),
+            MinskType::Int64 => (i64::MIN as i128, i64::MAX as i128),
+            _ => unreachable!("{} is not an integral type", ty),
+        };
+        Self::wrapping_integral(ty, value.clamp(min, max))
+    }
+
+    pub(crate) fn ty(&self) -> MinskType {
+        match self {
+            MinskValue::Int8(_) => MinskType::Int8,
+            MinskValue::Int16(_) => MinskType::Int16,
+            MinskValue::Integer(_) => MinskType::Integer,
+            MinskValue::UInt(_) => MinskType::UInt,
+            MinskValue::Int64(_) => MinskType::Int64,
+            MinskValue::Float(_) => MinskType::Float,
+            MinskValue::Boolean(_) => MinskType::Boolean,
+            MinskValue::Char(_) => MinskType::Char,
+            MinskValue::String(_) => MinskType::String,
+            MinskValue::Null => MinskType::Null,
+            MinskValue::Array(ty, _) | MinskValue::Struct(ty, _) => *ty,
+        }
+    }
+
     pub(crate) fn is_integer(&self) -> bool {
         matches!(self, Self::Integer(_))
     }
 
+    pub(crate) fn is_float(&self) -> bool {
+        matches!(self, Self::Float(_))
+    }
+
     pub(crate) fn is_boolean(&self) -> bool {
         matches!(self, Self::Boolean(_))
     }
 
+    pub(crate) fn is_string(&self) -> bool {
+        matches!(self, Self::String(_))
+    }
+
     pub(crate) fn is_null(&self) -> bool {
         matches!(self, Self::Null)
     }
@@ -27,20 +106,89 @@ impl MinskValue {
         }
     }
 
+    /// Widens a value of any integral type.
+    pub(crate) fn as_integral(&self) -> Option<i128> {
+        match self {
+            Self::Int8(i) => Some(*i as i128),
+            Self::Int16(i) => Some(*i as i128),
+            Self::Integer(i) => Some(*i as i128),
+            Self::UInt(i) => Some(*i as i128),
+            Self::Int64(i) => Some(*i as i128),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_float(&self) -> Option<f64> {
+        match self {
+            Self::Float(f) => Some(*f),
+            _ => None,
+        }
+    }
+
     pub(crate) fn as_boolean(&self) -> Option<bool> {
         match self {
             Self::Boolean(b) => Some(*b),
             _ => None,
         }
     }
+
+    pub(crate) fn as_char(&self) -> Option<char> {
+        match self {
+            Self::Char(c) => Some(*c),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_array(&self) -> Option<&ArrayElements> {
+        match self {
+            Self::Array(_, elements) => Some(elements),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_struct(&self) -> Option<&StructFields> {
+        match self {
+            Self::Struct(_, fields) => Some(fields),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_string(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
 }
 
 impl PartialEq for MinskValue {
     fn eq(&self, other: &Self) -> bool {
         match self {
+            MinskValue::Int8(_)
+            | MinskValue::Int16(_)
+            | MinskValue::UInt(_)
+            | MinskValue::Int64(_) => {
+                self.ty() == other.ty() && self.as_integral() == other.as_integral()
+            }
             MinskValue::Integer(i) => other.is_integer() && other.as_integer().unwrap() == *i,
+            MinskValue::Float(f) => other.is_float() && other.as_float().unwrap() == *f,
             MinskValue::Boolean(b) => other.is_boolean() && other.as_boolean().unwrap() == *b,
+            MinskValue::Char(c) => other.as_char() == Some(*c),
+            MinskValue::String(s) => other.is_string() && other.as_string().unwrap() == s,
             MinskValue::Null => other.is_null(),
+            MinskValue::Array(_, elements) => match other.as_array() {
+                Some(other_elements) => {
+                    Arc::ptr_eq(elements, other_elements)
+                        || *elements.read() == *other_elements.read()
+                }
+                None => false,
+            },
+            MinskValue::Struct(ty, fields) => match other.as_struct() {
+                Some(other_fields) if *ty == other.ty() => {
+                    Arc::ptr_eq(fields, other_fields) || *fields.read() == *other_fields.read()
+                }
+                _ => false,
+            },
         }
     }
 }
@@ -48,9 +196,44 @@ impl PartialEq for MinskValue {
 impl Display for MinskValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::Int8(i) => write!(f, "{}", i),
+            Self::Int16(i) => write!(f, "{}", i),
             Self::Integer(i) => write!(f, "{}", i),
+            Self::UInt(i) => write!(f, "{}", i),
+            Self::Int64(i) => write!(f, "{}", i),
+            // Debug keeps the fraction or exponent, so the output reads back as a float.
+            // infinity and NaN have no literals, so they print as divisions that produce them
+            Self::Float(x) if x.is_nan() => write!(f, "0.0 / 0.0"),
+            Self::Float(x) if x.is_infinite() && *x < 0.0 => write!(f, "-1.0 / 0.0"),
+            Self::Float(x) if x.is_infinite() => write!(f, "1.0 / 0.0"),
+            Self::Float(x) => write!(f, "{:?}", x),
             Self::Boolean(b) => write!(f, "{}", b),
+            Self::Char(c) => write!(f, "{}", c),
+            Self::String(s) => write!(f, "{}", s),
             Self::Null => write!(f, "null"),
+            Self::Array(_, elements) => {
+                write!(f, "[")?;
+                for (i, element) in elements.read().iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", element)?;
+                }
+                write!(f, "]")
+            }
+            Self::Struct(MinskType::Struct(symbol), fields) => {
+                write!(f, "{} {{ ", symbol.name())?;
+                for (i, ((name, _), value)) in
+                    symbol.fields().iter().zip(fields.read().iter()).enumerate()
+                {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: {}", name, value)?;
+                }
+                write!(f, " }}")
+            }
+            Self::Struct(ty, _) => unreachable!("{} is not a struct type", ty),
         }
     }
 }
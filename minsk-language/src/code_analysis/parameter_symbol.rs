@@ -0,0 +1,49 @@
+use std::hash::{Hash, Hasher};
+
+use super::{
+    minsk_type::MinskType,
+    variable_symbol::{VariableKind, VariableSymbol},
+};
+
+/// Parameters compare by name and type, so a built-in function can be recognized by its
+/// signature; the variable each one is bound to is created once and shared by every call.
+#[derive(Debug, Clone)]
+pub struct ParameterSymbol {
+    variable: VariableSymbol,
+}
+
+impl ParameterSymbol {
+    pub(crate) fn new(name: String, ty: MinskType) -> Self {
+        Self {
+            variable: VariableSymbol::new(name, true, ty, VariableKind::Local),
+        }
+    }
+
+    pub(crate) fn ty(&self) -> MinskType {
+        self.variable.ty()
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        self.variable.name()
+    }
+
+    /// The read-only local variable the parameter is bound to inside the function body.
+    pub(crate) fn variable(&self) -> VariableSymbol {
+        self.variable.clone()
+    }
+}
+
+impl PartialEq for ParameterSymbol {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name() && self.ty() == other.ty()
+    }
+}
+
+impl Eq for ParameterSymbol {}
+
+impl Hash for ParameterSymbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name().hash(state);
+        self.ty().hash(state);
+    }
+}
@@ -0,0 +1,97 @@
+use std::fmt::Display;
+
+use super::{minsk_type::MinskType, text::text_span::TextSpan};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuntimeErrorKind {
+    DivisionByZero,
+    Overflow,
+    InvalidConversion,
+    InvalidArgument,
+    IndexOutOfBounds,
+    ZeroStep,
+    UnassignedVariable,
+    StackOverflow,
+}
+
+#[derive(Debug, Clone)]
+pub struct RuntimeError {
+    pub span: TextSpan,
+    pub message: String,
+    pub kind: RuntimeErrorKind,
+}
+
+impl RuntimeError {
+    pub(crate) fn division_by_zero(span: TextSpan) -> Self {
+        Self {
+            span,
+            message: "Attempted to divide by zero".to_string(),
+            kind: RuntimeErrorKind::DivisionByZero,
+        }
+    }
+
+    pub(crate) fn overflow(span: TextSpan) -> Self {
+        Self {
+            span,
+            message: "Arithmetic operation resulted in an overflow".to_string(),
+            kind: RuntimeErrorKind::Overflow,
+        }
+    }
+
+    pub(crate) fn invalid_conversion(span: TextSpan, text: &str, ty: MinskType) -> Self {
+        Self {
+            span,
+            message: format!("Cannot convert '{}' to {}", text, ty),
+            kind: RuntimeErrorKind::InvalidConversion,
+        }
+    }
+
+    pub(crate) fn invalid_argument(span: TextSpan, message: String) -> Self {
+        Self {
+            span,
+            message,
+            kind: RuntimeErrorKind::InvalidArgument,
+        }
+    }
+
+    pub(crate) fn index_out_of_bounds(span: TextSpan, index: i32, length: usize) -> Self {
+        Self {
+            span,
+            message: format!(
+                "Index {} is out of bounds for an array of length {}",
+                index, length
+            ),
+            kind: RuntimeErrorKind::IndexOutOfBounds,
+        }
+    }
+
+    pub(crate) fn zero_step(span: TextSpan) -> Self {
+        Self {
+            span,
+            message: "The step of a 'for' loop cannot be zero".to_string(),
+            kind: RuntimeErrorKind::ZeroStep,
+        }
+    }
+
+    pub(crate) fn unassigned_variable(span: TextSpan, name: &str) -> Self {
+        Self {
+            span,
+            message: format!("Variable '{}' is used before it is assigned a value", name),
+            kind: RuntimeErrorKind::UnassignedVariable,
+        }
+    }
+
+    pub(crate) fn stack_overflow(span: TextSpan, depth: usize) -> Self {
+        Self {
+            span,
+            message: format!("Calls are nested more than {} deep", depth),
+            kind: RuntimeErrorKind::StackOverflow,
+        }
+    }
+}
+
+impl Display for RuntimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
@@ -0,0 +1,102 @@
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    sync::OnceLock,
+};
+
+use parking_lot::{Mutex, RwLock};
+
+use super::minsk_type::MinskType;
+
+/// A struct's name with its fields' names and types, as written in the declaration.
+pub(crate) type StructDeclaration = (String, Vec<(String, String)>);
+
+type Fields = &'static [(String, MinskType)];
+
+/// A struct type is its declaration, so symbols compare by id. That also keeps a struct
+/// with a field of its own type from being compared or hashed forever.
+pub struct StructSymbol {
+    id: usize,
+    name: String,
+}
+
+impl StructSymbol {
+    /// Leaks the symbol, so that types stay `Copy`. To keep a long REPL session from
+    /// growing without bound, symbols are remembered by their declaration, and a
+    /// submission declaring the same struct again can reuse one through [`Self::previous`].
+    pub(crate) fn declare(declaration: StructDeclaration) -> &'static StructSymbol {
+        let mut fields = Self::all_fields().write();
+        let symbol = Box::leak(Box::new(Self {
+            id: fields.len(),
+            name: declaration.0.clone(),
+        }));
+        fields.push(&[]);
+        Self::declarations().lock().insert(declaration, symbol);
+        symbol
+    }
+
+    /// The symbol last declared by an identical declaration. Its field types may still
+    /// differ, if a type they name has been declared again since.
+    pub(crate) fn previous(declaration: &StructDeclaration) -> Option<&'static StructSymbol> {
+        Self::declarations().lock().get(declaration).copied()
+    }
+
+    fn declarations() -> &'static Mutex<HashMap<StructDeclaration, &'static StructSymbol>> {
+        static DECLARATIONS: OnceLock<Mutex<HashMap<StructDeclaration, &'static StructSymbol>>> =
+            OnceLock::new();
+        DECLARATIONS.get_or_init(Default::default)
+    }
+
+    /// Every symbol's fields, by id. They are filled in once every struct in the submission
+    /// is declared, so that fields can refer to any of them, and are kept apart from the
+    /// symbols so that a symbol never changes while it is used as a key.
+    fn all_fields() -> &'static RwLock<Vec<Fields>> {
+        static FIELDS: OnceLock<RwLock<Vec<Fields>>> = OnceLock::new();
+        FIELDS.get_or_init(Default::default)
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn define_fields(&self, fields: Vec<(String, MinskType)>) {
+        Self::all_fields().write()[self.id] = Box::leak(fields.into_boxed_slice());
+    }
+
+    /// In declaration order, which is also the order of a value's fields.
+    pub(crate) fn fields(&self) -> Fields {
+        Self::all_fields().read()[self.id]
+    }
+
+    /// The index and type of the field called `name`.
+    pub(crate) fn field(&self, name: &str) -> Option<(usize, MinskType)> {
+        let fields = self.fields();
+        fields
+            .iter()
+            .position(|(n, _)| n == name)
+            .map(|i| (i, fields[i].1))
+    }
+}
+
+impl PartialEq for StructSymbol {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for StructSymbol {}
+
+impl Hash for StructSymbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state)
+    }
+}
+
+impl Debug for StructSymbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StructSymbol")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
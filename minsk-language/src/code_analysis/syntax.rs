@@ -1,22 +1,47 @@
+pub(super) mod array_expression_syntax;
+pub(super) mod array_type_syntax;
 pub(super) mod assignment_expression_syntax;
 pub(super) mod binary_expression_syntax;
 pub(super) mod block_statement_syntax;
+pub(super) mod break_statement_syntax;
+pub(super) mod call_expression_syntax;
 pub mod compilation_unit;
+pub(super) mod conditional_expression_syntax;
+pub(super) mod continue_statement_syntax;
+pub(super) mod do_while_statement_syntax;
 pub(super) mod expression_statement_syntax;
 pub(super) mod expression_syntax;
+pub(super) mod field_initializer_syntax;
+pub(super) mod field_syntax;
 pub(super) mod for_statement_syntax;
+pub(super) mod function_declaration_syntax;
+pub(super) mod global_statement_syntax;
 pub(super) mod if_statement_syntax;
+pub(super) mod increment_expression_syntax;
+pub(super) mod index_assignment_expression_syntax;
+pub(super) mod index_expression_syntax;
 mod lexer;
 pub(super) mod literal_expression_syntax;
+pub(super) mod member_access_expression_syntax;
+pub(super) mod member_assignment_expression_syntax;
+pub(super) mod member_syntax;
 pub(super) mod name_expression_syntax;
+pub(super) mod parameter_syntax;
 pub(super) mod parenthesized_expression_syntax;
 mod parser;
+pub(super) mod return_statement_syntax;
+pub(super) mod separated_syntax_list;
 pub(super) mod statement_syntax;
-mod syntax_facts;
+pub(super) mod struct_declaration_syntax;
+pub(super) mod struct_expression_syntax;
+pub(super) mod syntax_facts;
 pub(super) mod syntax_kind;
 pub mod syntax_node;
-mod syntax_token;
+pub(super) mod syntax_token;
 pub mod syntax_tree;
+pub(super) mod syntax_trivia;
+pub(super) mod type_clause_syntax;
+pub(super) mod type_syntax;
 pub(super) mod unary_expression_syntax;
 pub(super) mod variable_declaration_syntax;
 pub(super) mod while_statement_syntax;
@@ -0,0 +1,32 @@
+use std::fmt::Display;
+
+use crate::code_analysis::text::text_span::TextSpan;
+
+use super::{
+    expression_syntax::ExpressionSyntax, separated_syntax_list::SeparatedSyntaxList,
+    syntax_token::SyntaxToken,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArrayExpressionSyntax {
+    pub(crate) open_bracket_token: SyntaxToken,
+    pub(crate) elements: SeparatedSyntaxList<ExpressionSyntax>,
+    pub(crate) close_bracket_token: SyntaxToken,
+}
+
+impl ArrayExpressionSyntax {
+    pub fn span(&self) -> TextSpan {
+        TextSpan {
+            start: self.open_bracket_token.span.start,
+            end: self.close_bracket_token.span.end,
+        }
+    }
+}
+
+impl Display for ArrayExpressionSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "ArrayExpressionSyntax")?;
+        write!(f, "{}", self.elements)?;
+        Ok(())
+    }
+}
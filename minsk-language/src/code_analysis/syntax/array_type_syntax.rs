@@ -0,0 +1,43 @@
+use std::fmt::Display;
+
+use crate::code_analysis::text::text_span::TextSpan;
+
+use super::{syntax_token::SyntaxToken, type_syntax::TypeSyntax};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArrayTypeSyntax {
+    open_bracket_token: SyntaxToken,
+    element_type: Box<TypeSyntax>,
+    close_bracket_token: SyntaxToken,
+}
+
+impl ArrayTypeSyntax {
+    pub(crate) fn new(
+        open_bracket_token: SyntaxToken,
+        element_type: TypeSyntax,
+        close_bracket_token: SyntaxToken,
+    ) -> Self {
+        Self {
+            open_bracket_token,
+            element_type: Box::new(element_type),
+            close_bracket_token,
+        }
+    }
+
+    pub(crate) fn span(&self) -> TextSpan {
+        TextSpan {
+            start: self.open_bracket_token.span.start,
+            end: self.close_bracket_token.span.end,
+        }
+    }
+
+    pub(crate) fn element_type(&self) -> &TypeSyntax {
+        &self.element_type
+    }
+}
+
+impl Display for ArrayTypeSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}]", self.element_type)
+    }
+}
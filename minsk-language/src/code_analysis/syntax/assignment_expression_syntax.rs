@@ -7,7 +7,7 @@ use super::{expression_syntax::ExpressionSyntax, syntax_token::SyntaxToken};
 #[derive(Debug, Clone, PartialEq)]
 pub struct AssignmentExpressionSyntax {
     pub(crate) identifier_token: SyntaxToken,
-    pub(crate) equals_token: SyntaxToken,
+    pub(crate) assignment_token: SyntaxToken,
     pub(crate) expression: Box<ExpressionSyntax>,
 }
 
@@ -24,7 +24,7 @@ impl Display for AssignmentExpressionSyntax {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "AssignmentExpressionSyntax")?;
         writeln!(f, "    {}", self.identifier_token)?;
-        writeln!(f, "    {}", self.equals_token)?;
+        writeln!(f, "    {}", self.assignment_token)?;
         writeln!(f, "    {}", self.expression)?;
         Ok(())
     }
@@ -0,0 +1,34 @@
+use std::fmt::Display;
+
+use crate::code_analysis::text::text_span::TextSpan;
+
+use super::syntax_token::SyntaxToken;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BreakStatementSyntax {
+    keyword: SyntaxToken,
+    semicolon_token: Option<SyntaxToken>,
+}
+
+impl BreakStatementSyntax {
+    pub(crate) fn new(keyword: SyntaxToken, semicolon_token: Option<SyntaxToken>) -> Self {
+        Self {
+            keyword,
+            semicolon_token,
+        }
+    }
+
+    pub(crate) fn span(&self) -> TextSpan {
+        self.keyword.span
+    }
+
+    pub(crate) fn keyword(&self) -> &SyntaxToken {
+        &self.keyword
+    }
+}
+
+impl Display for BreakStatementSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "BreakStatementSyntax")
+    }
+}
@@ -0,0 +1,34 @@
+use std::fmt::Display;
+
+use crate::code_analysis::text::text_span::TextSpan;
+
+use super::{
+    expression_syntax::ExpressionSyntax, separated_syntax_list::SeparatedSyntaxList,
+    syntax_token::SyntaxToken,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallExpressionSyntax {
+    pub(crate) identifier_token: SyntaxToken,
+    pub(crate) open_parenthesis_token: SyntaxToken,
+    pub(crate) arguments: SeparatedSyntaxList<ExpressionSyntax>,
+    pub(crate) close_parenthesis_token: SyntaxToken,
+}
+
+impl CallExpressionSyntax {
+    pub fn span(&self) -> TextSpan {
+        TextSpan {
+            start: self.identifier_token.span.start,
+            end: self.close_parenthesis_token.span.end,
+        }
+    }
+}
+
+impl Display for CallExpressionSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "CallExpressionSyntax")?;
+        writeln!(f, "    {}", self.identifier_token)?;
+        write!(f, "{}", self.arguments)?;
+        Ok(())
+    }
+}
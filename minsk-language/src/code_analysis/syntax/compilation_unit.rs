@@ -2,24 +2,24 @@ use std::fmt::Display;
 
 use crate::code_analysis::text::text_span::TextSpan;
 
-use super::{statement_syntax::StatementSyntax, syntax_token::SyntaxToken};
+use super::{member_syntax::MemberSyntax, syntax_token::SyntaxToken};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct CompilationUnit {
-    statement: StatementSyntax,
+    members: Vec<MemberSyntax>,
     end_of_file_token: SyntaxToken,
 }
 
 impl CompilationUnit {
-    pub fn new(statement: StatementSyntax, end_of_file_token: SyntaxToken) -> Self {
+    pub fn new(members: Vec<MemberSyntax>, end_of_file_token: SyntaxToken) -> Self {
         Self {
-            statement,
+            members,
             end_of_file_token,
         }
     }
 
-    pub fn statement(&self) -> &StatementSyntax {
-        &self.statement
+    pub fn members(&self) -> &[MemberSyntax] {
+        &self.members
     }
 
     pub fn end_of_file_token(&self) -> &SyntaxToken {
@@ -28,7 +28,11 @@ impl CompilationUnit {
 
     pub fn span(&self) -> TextSpan {
         TextSpan {
-            start: self.statement.span().start,
+            start: self
+                .members
+                .first()
+                .map(|m| m.span().start)
+                .unwrap_or(self.end_of_file_token.span.start),
             end: self.end_of_file_token.span.end,
         }
     }
@@ -36,7 +40,9 @@ impl CompilationUnit {
 
 impl Display for CompilationUnit {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.statement)?;
+        for member in &self.members {
+            write!(f, "{}", member)?;
+        }
         write!(f, "{}", self.end_of_file_token)?;
         Ok(())
     }
@@ -0,0 +1,34 @@
+use std::fmt::Display;
+
+use crate::code_analysis::text::text_span::TextSpan;
+
+use super::{expression_syntax::ExpressionSyntax, syntax_token::SyntaxToken};
+
+/// `condition ? when_true : when_false`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConditionalExpressionSyntax {
+    pub(crate) condition: Box<ExpressionSyntax>,
+    pub(crate) question_token: SyntaxToken,
+    pub(crate) when_true: Box<ExpressionSyntax>,
+    pub(crate) colon_token: SyntaxToken,
+    pub(crate) when_false: Box<ExpressionSyntax>,
+}
+
+impl ConditionalExpressionSyntax {
+    pub fn span(&self) -> TextSpan {
+        TextSpan {
+            start: self.condition.span().start,
+            end: self.when_false.span().end,
+        }
+    }
+}
+
+impl Display for ConditionalExpressionSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "ConditionalExpressionSyntax")?;
+        writeln!(f, "    {}", self.condition)?;
+        writeln!(f, "    {}", self.when_true)?;
+        writeln!(f, "    {}", self.when_false)?;
+        Ok(())
+    }
+}
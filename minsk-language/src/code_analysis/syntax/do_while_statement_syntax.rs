@@ -0,0 +1,59 @@
+use std::fmt::Display;
+
+use crate::code_analysis::text::text_span::TextSpan;
+
+use super::{
+    expression_syntax::ExpressionSyntax, statement_syntax::StatementSyntax,
+    syntax_token::SyntaxToken,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DoWhileStatementSyntax {
+    do_keyword: SyntaxToken,
+    body: Box<StatementSyntax>,
+    while_keyword: SyntaxToken,
+    condition: ExpressionSyntax,
+    semicolon_token: Option<SyntaxToken>,
+}
+
+impl DoWhileStatementSyntax {
+    pub(crate) fn new(
+        do_keyword: SyntaxToken,
+        body: Box<StatementSyntax>,
+        while_keyword: SyntaxToken,
+        condition: ExpressionSyntax,
+        semicolon_token: Option<SyntaxToken>,
+    ) -> Self {
+        Self {
+            do_keyword,
+            body,
+            while_keyword,
+            condition,
+            semicolon_token,
+        }
+    }
+
+    pub(crate) fn span(&self) -> TextSpan {
+        TextSpan {
+            start: self.do_keyword.span.start,
+            end: self.condition.span().end,
+        }
+    }
+
+    pub(crate) fn body(&self) -> &StatementSyntax {
+        &self.body
+    }
+
+    pub(crate) fn condition(&self) -> &ExpressionSyntax {
+        &self.condition
+    }
+}
+
+impl Display for DoWhileStatementSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "DoWhileStatementSyntax")?;
+        writeln!(f, "    {}", self.body)?;
+        writeln!(f, "{}", self.condition)?;
+        Ok(())
+    }
+}
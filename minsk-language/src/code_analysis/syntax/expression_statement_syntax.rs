@@ -2,16 +2,20 @@ use std::fmt::Display;
 
 use crate::code_analysis::text::text_span::TextSpan;
 
-use super::expression_syntax::ExpressionSyntax;
+use super::{expression_syntax::ExpressionSyntax, syntax_token::SyntaxToken};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ExpressionStatementSyntax {
     expression: ExpressionSyntax,
+    semicolon_token: Option<SyntaxToken>,
 }
 
 impl ExpressionStatementSyntax {
-    pub(crate) fn new(expression: ExpressionSyntax) -> Self {
-        Self { expression }
+    pub(crate) fn new(expression: ExpressionSyntax, semicolon_token: Option<SyntaxToken>) -> Self {
+        Self {
+            expression,
+            semicolon_token,
+        }
     }
 
     pub(crate) fn expression(&self) -> &ExpressionSyntax {
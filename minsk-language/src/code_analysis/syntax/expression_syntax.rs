@@ -3,11 +3,19 @@ use std::fmt::Display;
 use crate::code_analysis::text::text_span::TextSpan;
 
 use super::{
+    array_expression_syntax::ArrayExpressionSyntax,
     assignment_expression_syntax::AssignmentExpressionSyntax,
-    binary_expression_syntax::BinaryExpressionSyntax,
+    binary_expression_syntax::BinaryExpressionSyntax, call_expression_syntax::CallExpressionSyntax,
+    conditional_expression_syntax::ConditionalExpressionSyntax,
+    increment_expression_syntax::IncrementExpressionSyntax,
+    index_assignment_expression_syntax::IndexAssignmentExpressionSyntax,
+    index_expression_syntax::IndexExpressionSyntax,
     literal_expression_syntax::LiteralExpressionSyntax,
+    member_access_expression_syntax::MemberAccessExpressionSyntax,
+    member_assignment_expression_syntax::MemberAssignmentExpressionSyntax,
     name_expression_syntax::NameExpressionSyntax,
     parenthesized_expression_syntax::ParenthesizedExpressionSyntax,
+    struct_expression_syntax::StructExpressionSyntax,
     unary_expression_syntax::UnaryExpressionSyntax,
 };
 
@@ -19,6 +27,15 @@ pub enum ExpressionSyntax {
     Parenthesized(ParenthesizedExpressionSyntax),
     Name(NameExpressionSyntax),
     Assignment(AssignmentExpressionSyntax),
+    Call(CallExpressionSyntax),
+    Increment(IncrementExpressionSyntax),
+    Array(ArrayExpressionSyntax),
+    Index(IndexExpressionSyntax),
+    IndexAssignment(IndexAssignmentExpressionSyntax),
+    Struct(StructExpressionSyntax),
+    MemberAccess(MemberAccessExpressionSyntax),
+    MemberAssignment(MemberAssignmentExpressionSyntax),
+    Conditional(ConditionalExpressionSyntax),
 }
 
 impl ExpressionSyntax {
@@ -30,6 +47,15 @@ impl ExpressionSyntax {
             ExpressionSyntax::Parenthesized(p) => p.span(),
             ExpressionSyntax::Name(n) => n.span(),
             ExpressionSyntax::Assignment(a) => a.span(),
+            ExpressionSyntax::Call(c) => c.span(),
+            ExpressionSyntax::Increment(i) => i.span(),
+            ExpressionSyntax::Array(a) => a.span(),
+            ExpressionSyntax::Index(i) => i.span(),
+            ExpressionSyntax::IndexAssignment(a) => a.span(),
+            ExpressionSyntax::Struct(s) => s.span(),
+            ExpressionSyntax::MemberAccess(m) => m.span(),
+            ExpressionSyntax::MemberAssignment(a) => a.span(),
+            ExpressionSyntax::Conditional(c) => c.span(),
         }
     }
 }
@@ -51,6 +77,15 @@ impl Display for ExpressionSyntax {
             ExpressionSyntax::Parenthesized(p) => format_indented(p, f),
             ExpressionSyntax::Name(n) => format_indented(n, f),
             ExpressionSyntax::Assignment(a) => format_indented(a, f),
+            ExpressionSyntax::Call(c) => format_indented(c, f),
+            ExpressionSyntax::Increment(i) => format_indented(i, f),
+            ExpressionSyntax::Array(a) => format_indented(a, f),
+            ExpressionSyntax::Index(i) => format_indented(i, f),
+            ExpressionSyntax::IndexAssignment(a) => format_indented(a, f),
+            ExpressionSyntax::Struct(s) => format_indented(s, f),
+            ExpressionSyntax::MemberAccess(m) => format_indented(m, f),
+            ExpressionSyntax::MemberAssignment(a) => format_indented(a, f),
+            ExpressionSyntax::Conditional(c) => format_indented(c, f),
         }
     }
 }
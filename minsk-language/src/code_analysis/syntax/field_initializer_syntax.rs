@@ -0,0 +1,30 @@
+use std::fmt::Display;
+
+use crate::code_analysis::text::text_span::TextSpan;
+
+use super::{expression_syntax::ExpressionSyntax, syntax_token::SyntaxToken};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldInitializerSyntax {
+    pub(crate) identifier_token: SyntaxToken,
+    pub(crate) colon_token: SyntaxToken,
+    pub(crate) expression: ExpressionSyntax,
+}
+
+impl FieldInitializerSyntax {
+    pub fn span(&self) -> TextSpan {
+        TextSpan {
+            start: self.identifier_token.span.start,
+            end: self.expression.span().end,
+        }
+    }
+}
+
+impl Display for FieldInitializerSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "FieldInitializerSyntax")?;
+        writeln!(f, "    {}", self.identifier_token)?;
+        writeln!(f, "    {}", self.expression)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use std::fmt::Display;
+
+use crate::code_analysis::text::text_span::TextSpan;
+
+use super::{syntax_token::SyntaxToken, type_clause_syntax::TypeClauseSyntax};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldSyntax {
+    identifier: SyntaxToken,
+    type_clause: TypeClauseSyntax,
+}
+
+impl FieldSyntax {
+    pub(crate) fn new(identifier: SyntaxToken, type_clause: TypeClauseSyntax) -> Self {
+        Self {
+            identifier,
+            type_clause,
+        }
+    }
+
+    pub(crate) fn span(&self) -> TextSpan {
+        TextSpan {
+            start: self.identifier.span.start,
+            end: self.type_clause.span().end,
+        }
+    }
+
+    pub(crate) fn identifier(&self) -> &SyntaxToken {
+        &self.identifier
+    }
+
+    pub(crate) fn type_clause(&self) -> &TypeClauseSyntax {
+        &self.type_clause
+    }
+}
+
+impl Display for FieldSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "FieldSyntax({}, {})",
+            self.identifier.text, self.type_clause
+        )
+    }
+}
@@ -15,10 +15,12 @@ pub struct ForStatementSyntax {
     lower_bound: Box<ExpressionSyntax>,
     to_keyword: SyntaxToken,
     upper_bound: Box<ExpressionSyntax>,
+    step_clause: Option<StepClauseSyntax>,
     body: Box<StatementSyntax>,
 }
 
 impl ForStatementSyntax {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         for_keyword: SyntaxToken,
         identifier: SyntaxToken,
@@ -26,6 +28,7 @@ impl ForStatementSyntax {
         lower_bound: Box<ExpressionSyntax>,
         to_keyword: SyntaxToken,
         upper_bound: Box<ExpressionSyntax>,
+        step_clause: Option<StepClauseSyntax>,
         body: Box<StatementSyntax>,
     ) -> Self {
         Self {
@@ -35,6 +38,7 @@ impl ForStatementSyntax {
             lower_bound,
             to_keyword,
             upper_bound,
+            step_clause,
             body,
         }
     }
@@ -42,7 +46,10 @@ impl ForStatementSyntax {
     pub(crate) fn span(&self) -> TextSpan {
         TextSpan {
             start: self.for_keyword.span.start,
-            end: self.upper_bound.span().end,
+            end: match &self.step_clause {
+                Some(s) => s.span().end,
+                None => self.upper_bound.span().end,
+            },
         }
     }
 
@@ -58,6 +65,10 @@ impl ForStatementSyntax {
         &self.upper_bound
     }
 
+    pub(crate) fn step_clause(&self) -> Option<&StepClauseSyntax> {
+        self.step_clause.as_ref()
+    }
+
     pub(crate) fn body(&self) -> &StatementSyntax {
         &self.body
     }
@@ -67,6 +78,38 @@ impl Display for ForStatementSyntax {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "ForStatementSyntax")?;
         writeln!(f, "{}..{}", self.lower_bound, self.upper_bound)?;
+        if let Some(s) = &self.step_clause {
+            writeln!(f, "    {}", s)?;
+        }
         Ok(())
     }
 }
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StepClauseSyntax {
+    step_keyword: SyntaxToken,
+    step: Box<ExpressionSyntax>,
+}
+
+impl StepClauseSyntax {
+    pub(crate) fn span(&self) -> TextSpan {
+        TextSpan {
+            start: self.step_keyword.span.start,
+            end: self.step.span().end,
+        }
+    }
+
+    pub(crate) fn new(step_keyword: SyntaxToken, step: Box<ExpressionSyntax>) -> Self {
+        Self { step_keyword, step }
+    }
+
+    pub(crate) fn step(&self) -> &ExpressionSyntax {
+        &self.step
+    }
+}
+
+impl Display for StepClauseSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "StepClauseSyntax({})", self.step)
+    }
+}
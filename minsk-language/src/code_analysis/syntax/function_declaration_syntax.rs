@@ -0,0 +1,77 @@
+use std::fmt::Display;
+
+use crate::code_analysis::text::text_span::TextSpan;
+
+use super::{
+    block_statement_syntax::BlockStatementSyntax, parameter_syntax::ParameterSyntax,
+    separated_syntax_list::SeparatedSyntaxList, syntax_token::SyntaxToken,
+    type_clause_syntax::TypeClauseSyntax,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionDeclarationSyntax {
+    function_keyword: SyntaxToken,
+    identifier: SyntaxToken,
+    open_parenthesis_token: SyntaxToken,
+    parameters: SeparatedSyntaxList<ParameterSyntax>,
+    close_parenthesis_token: SyntaxToken,
+    type_clause: Option<TypeClauseSyntax>,
+    body: BlockStatementSyntax,
+}
+
+impl FunctionDeclarationSyntax {
+    pub(crate) fn new(
+        function_keyword: SyntaxToken,
+        identifier: SyntaxToken,
+        open_parenthesis_token: SyntaxToken,
+        parameters: SeparatedSyntaxList<ParameterSyntax>,
+        close_parenthesis_token: SyntaxToken,
+        type_clause: Option<TypeClauseSyntax>,
+        body: BlockStatementSyntax,
+    ) -> Self {
+        Self {
+            function_keyword,
+            identifier,
+            open_parenthesis_token,
+            parameters,
+            close_parenthesis_token,
+            type_clause,
+            body,
+        }
+    }
+
+    pub(crate) fn span(&self) -> TextSpan {
+        TextSpan {
+            start: self.function_keyword.span.start,
+            end: self.body.span().end,
+        }
+    }
+
+    pub(crate) fn identifier(&self) -> &SyntaxToken {
+        &self.identifier
+    }
+
+    pub(crate) fn parameters(&self) -> &SeparatedSyntaxList<ParameterSyntax> {
+        &self.parameters
+    }
+
+    pub(crate) fn type_clause(&self) -> Option<&TypeClauseSyntax> {
+        self.type_clause.as_ref()
+    }
+
+    pub(crate) fn body(&self) -> &BlockStatementSyntax {
+        &self.body
+    }
+}
+
+impl Display for FunctionDeclarationSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "FunctionDeclarationSyntax({})", self.identifier.text)?;
+        write!(f, "{}", self.parameters)?;
+        if let Some(type_clause) = &self.type_clause {
+            writeln!(f, "    {}", type_clause)?;
+        }
+        writeln!(f, "    {}", self.body)?;
+        Ok(())
+    }
+}
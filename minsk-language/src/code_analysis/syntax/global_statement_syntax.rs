@@ -0,0 +1,32 @@
+use std::fmt::Display;
+
+use crate::code_analysis::text::text_span::TextSpan;
+
+use super::statement_syntax::StatementSyntax;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlobalStatementSyntax {
+    statement: Box<StatementSyntax>,
+}
+
+impl GlobalStatementSyntax {
+    pub(crate) fn new(statement: StatementSyntax) -> Self {
+        Self {
+            statement: Box::new(statement),
+        }
+    }
+
+    pub(crate) fn statement(&self) -> &StatementSyntax {
+        &self.statement
+    }
+
+    pub(crate) fn span(&self) -> TextSpan {
+        self.statement.span()
+    }
+}
+
+impl Display for GlobalStatementSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.statement)
+    }
+}
@@ -0,0 +1,43 @@
+use std::fmt::Display;
+
+use crate::code_analysis::text::text_span::TextSpan;
+
+use super::syntax_token::SyntaxToken;
+
+/// `++x`, `x++`, `--x` or `x--`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncrementExpressionSyntax {
+    pub(crate) identifier_token: SyntaxToken,
+    pub(crate) operator_token: SyntaxToken,
+    pub(crate) is_postfix: bool,
+}
+
+impl IncrementExpressionSyntax {
+    pub fn span(&self) -> TextSpan {
+        if self.is_postfix {
+            TextSpan {
+                start: self.identifier_token.span.start,
+                end: self.operator_token.span.end,
+            }
+        } else {
+            TextSpan {
+                start: self.operator_token.span.start,
+                end: self.identifier_token.span.end,
+            }
+        }
+    }
+}
+
+impl Display for IncrementExpressionSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "IncrementExpressionSyntax")?;
+        if self.is_postfix {
+            writeln!(f, "    {}", self.identifier_token)?;
+            writeln!(f, "    {}", self.operator_token)?;
+        } else {
+            writeln!(f, "    {}", self.operator_token)?;
+            writeln!(f, "    {}", self.identifier_token)?;
+        }
+        Ok(())
+    }
+}
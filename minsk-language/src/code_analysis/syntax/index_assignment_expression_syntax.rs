@@ -0,0 +1,34 @@
+use std::fmt::Display;
+
+use crate::code_analysis::text::text_span::TextSpan;
+
+use super::{
+    expression_syntax::ExpressionSyntax, index_expression_syntax::IndexExpressionSyntax,
+    syntax_token::SyntaxToken,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexAssignmentExpressionSyntax {
+    pub(crate) target: IndexExpressionSyntax,
+    pub(crate) assignment_token: SyntaxToken,
+    pub(crate) expression: Box<ExpressionSyntax>,
+}
+
+impl IndexAssignmentExpressionSyntax {
+    pub fn span(&self) -> TextSpan {
+        TextSpan {
+            start: self.target.span().start,
+            end: self.expression.span().end,
+        }
+    }
+}
+
+impl Display for IndexAssignmentExpressionSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "IndexAssignmentExpressionSyntax")?;
+        writeln!(f, "    {}", self.target)?;
+        writeln!(f, "    {}", self.assignment_token)?;
+        writeln!(f, "    {}", self.expression)?;
+        Ok(())
+    }
+}
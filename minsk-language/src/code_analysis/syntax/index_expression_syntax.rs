@@ -0,0 +1,31 @@
+use std::fmt::Display;
+
+use crate::code_analysis::text::text_span::TextSpan;
+
+use super::{expression_syntax::ExpressionSyntax, syntax_token::SyntaxToken};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexExpressionSyntax {
+    pub(crate) expression: Box<ExpressionSyntax>,
+    pub(crate) open_bracket_token: SyntaxToken,
+    pub(crate) index: Box<ExpressionSyntax>,
+    pub(crate) close_bracket_token: SyntaxToken,
+}
+
+impl IndexExpressionSyntax {
+    pub fn span(&self) -> TextSpan {
+        TextSpan {
+            start: self.expression.span().start,
+            end: self.close_bracket_token.span.end,
+        }
+    }
+}
+
+impl Display for IndexExpressionSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "IndexExpressionSyntax")?;
+        writeln!(f, "    {}", self.expression)?;
+        writeln!(f, "    {}", self.index)?;
+        Ok(())
+    }
+}
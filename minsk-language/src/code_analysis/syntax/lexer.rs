@@ -13,6 +13,8 @@ pub(super) struct Lexer {
     position: usize,
     kind: SyntaxKind,
     value: Option<MinskValue>,
+    /// Set when the text ends inside a token that more text could finish.
+    unexpected_end_of_file: bool,
     diagnostics: DiagnosticBag,
 }
 
@@ -24,6 +26,7 @@ impl Lexer {
             position: 0,
             kind: SyntaxKind::BadToken,
             value: None,
+            unexpected_end_of_file: false,
             diagnostics: DiagnosticBag::new(),
         }
     }
@@ -38,6 +41,10 @@ impl Lexer {
         self.position += 1;
     }
 
+    pub(super) fn unexpected_end_of_file(&self) -> bool {
+        self.unexpected_end_of_file
+    }
+
     pub(super) fn diagnostics(self) -> DiagnosticBag {
         self.diagnostics
     }
@@ -48,24 +55,58 @@ impl Lexer {
         self.value = None;
         match self.current() {
             '\0' => self.kind = SyntaxKind::EndOfFile,
-            d if d.is_numeric() => self.read_number_token(),
+            d if d.is_ascii_digit() => self.read_number_token(),
             w if w.is_whitespace() => self.read_whitespace(),
             l if l.is_alphabetic() => self.read_identifier_or_keyword(),
-            '+' => {
-                self.kind = SyntaxKind::Plus;
-                self.next();
-            }
-            '-' => {
-                self.kind = SyntaxKind::Minus;
-                self.next();
-            }
+            '"' => self.read_string(),
+            '\'' => self.read_char(),
+            '+' => match self.lookahead() {
+                '+' => {
+                    self.kind = SyntaxKind::PlusPlus;
+                    self.position += 2;
+                }
+                '=' => {
+                    self.kind = SyntaxKind::PlusEquals;
+                    self.position += 2;
+                }
+                _ => {
+                    self.kind = SyntaxKind::Plus;
+                    self.next();
+                }
+            },
+            '-' => match self.lookahead() {
+                '-' => {
+                    self.kind = SyntaxKind::MinusMinus;
+                    self.position += 2;
+                }
+                '=' => {
+                    self.kind = SyntaxKind::MinusEquals;
+                    self.position += 2;
+                }
+                _ => {
+                    self.kind = SyntaxKind::Minus;
+                    self.next();
+                }
+            },
             '*' => {
-                self.kind = SyntaxKind::Star;
-                self.next();
+                if self.lookahead() == '=' {
+                    self.kind = SyntaxKind::StarEquals;
+                    self.position += 2;
+                } else {
+                    self.kind = SyntaxKind::Star;
+                    self.next();
+                }
             }
+            '/' if self.lookahead() == '/' => self.read_single_line_comment(),
+            '/' if self.lookahead() == '*' => self.read_multi_line_comment(),
             '/' => {
-                self.kind = SyntaxKind::Slash;
-                self.next();
+                if self.lookahead() == '=' {
+                    self.kind = SyntaxKind::SlashEquals;
+                    self.position += 2;
+                } else {
+                    self.kind = SyntaxKind::Slash;
+                    self.next();
+                }
             }
             '(' => {
                 self.kind = SyntaxKind::OpenParenthesis;
@@ -83,6 +124,46 @@ impl Lexer {
                 self.kind = SyntaxKind::CloseBrace;
                 self.next();
             }
+            '[' => {
+                self.kind = SyntaxKind::OpenBracket;
+                self.next();
+            }
+            ']' => {
+                self.kind = SyntaxKind::CloseBracket;
+                self.next();
+            }
+            ':' => {
+                self.kind = SyntaxKind::Colon;
+                self.next();
+            }
+            ',' => {
+                self.kind = SyntaxKind::Comma;
+                self.next();
+            }
+            '.' => {
+                self.kind = SyntaxKind::Dot;
+                self.next();
+            }
+            '?' => {
+                self.kind = SyntaxKind::QuestionMark;
+                self.next();
+            }
+            ';' => {
+                self.kind = SyntaxKind::Semicolon;
+                self.next();
+            }
+            '%' => {
+                self.kind = SyntaxKind::Percent;
+                self.next();
+            }
+            '~' => {
+                self.kind = SyntaxKind::Tilde;
+                self.next();
+            }
+            '^' => {
+                self.kind = SyntaxKind::Hat;
+                self.next();
+            }
             '!' => {
                 if self.lookahead() == '=' {
                     self.kind = SyntaxKind::BangEquals;
@@ -92,31 +173,51 @@ impl Lexer {
                     self.next();
                 }
             }
-            '<' => {
-                if self.lookahead() == '=' {
+            '<' => match self.lookahead() {
+                '=' => {
                     self.kind = SyntaxKind::LessEquals;
                     self.position += 2;
-                } else {
+                }
+                '<' => {
+                    self.kind = SyntaxKind::LessLess;
+                    self.position += 2;
+                }
+                _ => {
                     self.kind = SyntaxKind::Less;
                     self.next();
                 }
-            }
-            '>' => {
-                if self.lookahead() == '=' {
+            },
+            '>' => match self.lookahead() {
+                '=' => {
                     self.kind = SyntaxKind::GreaterEquals;
                     self.position += 2;
-                } else {
+                }
+                '>' => {
+                    self.kind = SyntaxKind::GreaterGreater;
+                    self.position += 2;
+                }
+                _ => {
                     self.kind = SyntaxKind::Greater;
                     self.next();
                 }
+            },
+            '&' => {
+                if self.lookahead() == '&' {
+                    self.kind = SyntaxKind::AmpersandAmpersand;
+                    self.position += 2;
+                } else {
+                    self.kind = SyntaxKind::Ampersand;
+                    self.next();
+                }
             }
-            '&' if self.lookahead() == '&' => {
-                self.kind = SyntaxKind::AmpersandAmpersand;
-                self.position += 2;
-            }
-            '|' if self.lookahead() == '|' => {
-                self.kind = SyntaxKind::PipePipe;
-                self.position += 2;
+            '|' => {
+                if self.lookahead() == '|' {
+                    self.kind = SyntaxKind::PipePipe;
+                    self.position += 2;
+                } else {
+                    self.kind = SyntaxKind::Pipe;
+                    self.next();
+                }
             }
             '=' => {
                 if self.lookahead() == '=' {
@@ -147,31 +248,290 @@ impl Lexer {
     }
 
     fn read_number_token(&mut self) {
-        while self.current().is_numeric() {
+        let radix = match (self.current(), self.lookahead()) {
+            ('0', 'x' | 'X') => Some((16, "hexadecimal")),
+            ('0', 'b' | 'B') => Some((2, "binary")),
+            ('0', 'o' | 'O') => Some((8, "octal")),
+            _ => None,
+        };
+        if let Some((radix, name)) = radix {
+            self.read_radix_number(radix, name);
+            return;
+        }
+
+        let mut misplaced_separator = self.read_digits();
+        let mut is_float = false;
+        if self.current() == '.' && self.digits_follow(1) {
+            is_float = true;
             self.next();
+            misplaced_separator = misplaced_separator.or(self.read_digits());
+        }
+        // an `e` without digits after it belongs to whatever follows, as in `1else`
+        let sign = usize::from(matches!(self.lookahead(), '+' | '-'));
+        if matches!(self.current(), 'e' | 'E') && self.digits_follow(1 + sign) {
+            is_float = true;
+            self.position += 1 + sign;
+            misplaced_separator = misplaced_separator.or(self.read_digits());
         }
 
-        let text = self.text[TextSpan {
+        let span = TextSpan {
             start: self.start,
             end: self.position,
+        };
+        let text = self.text[span].iter().collect::<String>();
+        let digits = text.replace('_', "");
+        // an invalid number is still a number token, so the parser doesn't report it again
+        self.value = if let Some(position) = misplaced_separator {
+            if position + 1 == self.position {
+                self.report_trailing_separator();
+            } else {
+                self.diagnostics.report_misplaced_separator(TextSpan {
+                    start: position,
+                    end: position + 1,
+                });
+            }
+            None
+        } else if is_float {
+            match digits.parse::<f64>() {
+                Ok(v) if v.is_finite() => Some(MinskValue::Float(v)),
+                Ok(_) => {
+                    self.diagnostics
+                        .report_number_overflow(span, &text, MinskType::Float);
+                    None
+                }
+                Err(_) => {
+                    self.diagnostics
+                        .report_invalid_number(span, &text, MinskType::Float);
+                    None
+                }
+            }
+        } else {
+            // only ASCII digits are left, so the parse can only fail by overflowing
+            match digits.parse::<i64>() {
+                Ok(v) => Some(Self::integer_value(v)),
+                Err(_) => {
+                    self.diagnostics
+                        .report_number_overflow(span, &text, MinskType::Int64);
+                    None
+                }
+            }
+        };
+        self.kind = SyntaxKind::Number;
+    }
+
+    /// Digits may be grouped with underscores, as in `1_000_000`. Returns the position of the
+    /// first underscore that isn't between two digits.
+    fn read_digits(&mut self) -> Option<usize> {
+        let mut misplaced_separator = None;
+        while self.current().is_ascii_digit() || self.current() == '_' {
+            // a number starts with a digit, so an underscore always has a character before it
+            if self.current() == '_'
+                && !(self
+                    .text
+                    .get(self.position - 1)
+                    .is_some_and(|c| c.is_ascii_digit())
+                    && self.lookahead().is_ascii_digit())
+            {
+                misplaced_separator = misplaced_separator.or(Some(self.position));
+            }
+            self.next();
+        }
+        misplaced_separator
+    }
+
+    /// Whether a digit, possibly after some underscores, starts `offset` characters ahead.
+    fn digits_follow(&self, offset: usize) -> bool {
+        let mut position = self.position + offset;
+        while self.text.get(position) == Some('_') {
+            position += 1;
+        }
+        self.text.get(position).is_some_and(|c| c.is_ascii_digit())
+    }
+
+    /// Reads a `0x`, `0b` or `0o` literal. Letters and digits that don't belong to the radix
+    /// are still part of the token, so that they are reported here rather than as a separate
+    /// identifier.
+    fn read_radix_number(&mut self, radix: u32, name: &str) {
+        // skip the prefix
+        self.position += 2;
+        while self.current().is_ascii_alphanumeric() || self.current() == '_' {
+            self.next();
+        }
+        self.kind = SyntaxKind::Number;
+
+        let span = TextSpan {
+            start: self.start,
+            end: self.position,
+        };
+        let text = self.text[span].iter().collect::<String>();
+        let digits = &text[2..];
+        if let Some((i, c)) = digits
+            .char_indices()
+            .find(|&(_, c)| c != '_' && !c.is_digit(radix))
+        {
+            let position = self.start + 2 + i;
+            let span = TextSpan {
+                start: position,
+                end: position + 1,
+            };
+            self.diagnostics.report_invalid_digit(span, c, name);
+            return;
+        }
+        if digits.ends_with('_') {
+            self.report_trailing_separator();
+            return;
+        }
+        if !digits.chars().any(|c| c.is_digit(radix)) {
+            self.diagnostics
+                .report_missing_digits(span, &text[..2], name);
+            return;
+        }
+        match i64::from_str_radix(&digits.replace('_', ""), radix) {
+            Ok(v) => self.value = Some(Self::integer_value(v)),
+            Err(_) => self
+                .diagnostics
+                .report_number_overflow(span, &text, MinskType::Int64),
+        }
+    }
+
+    /// Reports the `_` that the number just read ends with.
+    fn report_trailing_separator(&mut self) {
+        self.diagnostics.report_trailing_separator(TextSpan {
+            start: self.position - 1,
+            end: self.position,
+        });
+    }
+
+    /// A literal too large for an int is an int64, like in C#.
+    fn integer_value(value: i64) -> MinskValue {
+        MinskValue::integral(MinskType::Integer, value as i128).unwrap_or(MinskValue::Int64(value))
+    }
+
+    fn read_string(&mut self) {
+        // skip the opening quote
+        self.next();
+        let mut value = String::new();
+        loop {
+            match self.current() {
+                '\0' | '\r' | '\n' => {
+                    self.diagnostics.report_unterminated_string(self.start);
+                    break;
+                }
+                '"' => {
+                    self.next();
+                    break;
+                }
+                '\\' => {
+                    if let Some(c) = self.read_escape_sequence() {
+                        value.push(c);
+                    }
+                }
+                c => {
+                    value.push(c);
+                    self.next();
+                }
+            }
+        }
+        self.kind = SyntaxKind::String;
+        self.value = Some(MinskValue::String(value));
+    }
+
+    fn read_char(&mut self) {
+        // skip the opening quote
+        self.next();
+        let mut value = None;
+        // escape sequences count as one character, even when they're invalid
+        let mut length = 0;
+        loop {
+            match self.current() {
+                '\0' | '\r' | '\n' => {
+                    self.diagnostics.report_unterminated_char(self.start);
+                    length = 1;
+                    break;
+                }
+                '\'' => {
+                    self.next();
+                    break;
+                }
+                '\\' => {
+                    value = value.or(self.read_escape_sequence());
+                    length += 1;
+                }
+                c => {
+                    value = value.or(Some(c));
+                    length += 1;
+                    self.next();
+                }
+            }
+        }
+        if length != 1 {
+            self.diagnostics.report_invalid_char_length(TextSpan {
+                start: self.start,
+                end: self.position,
+            });
+        }
+        self.kind = SyntaxKind::Char;
+        self.value = Some(MinskValue::Char(value.unwrap_or('\0')));
+    }
+
+    fn read_escape_sequence(&mut self) -> Option<char> {
+        let start = self.position;
+        // skip the backslash
+        self.next();
+        let escaped = match self.current() {
+            '"' => '"',
+            '\'' => '\'',
+            '\\' => '\\',
+            'n' => '\n',
+            't' => '\t',
+            'u' if self.lookahead() == '{' => return self.read_unicode_escape(start),
+            // the literal itself is unterminated, which the caller reports
+            '\0' | '\r' | '\n' => return None,
+            _ => {
+                self.next();
+                self.report_invalid_escape_sequence(start);
+                return None;
+            }
+        };
+        self.next();
+        Some(escaped)
+    }
+
+    fn read_unicode_escape(&mut self, start: usize) -> Option<char> {
+        // skip the 'u{'
+        self.position += 2;
+        let digits_start = self.position;
+        while self.current().is_ascii_hexdigit() {
+            self.next();
+        }
+        let digits = self.text[TextSpan {
+            start: digits_start,
+            end: self.position,
         }]
         .iter()
         .collect::<String>();
-        self.value = match text.parse() {
-            Ok(v) => Some(MinskValue::Integer(v)),
-            Err(_) => {
-                self.diagnostics.report_invalid_number(
-                    TextSpan {
-                        start: self.start,
-                        end: self.position,
-                    },
-                    &text,
-                    MinskType::Integer,
-                );
-                return;
-            }
+        if self.current() != '}' {
+            self.report_invalid_escape_sequence(start);
+            return None;
+        }
+        self.next();
+
+        let escaped = u32::from_str_radix(&digits, 16)
+            .ok()
+            .and_then(std::char::from_u32);
+        if escaped.is_none() {
+            self.report_invalid_escape_sequence(start);
+        }
+        escaped
+    }
+
+    fn report_invalid_escape_sequence(&mut self, start: usize) {
+        let span = TextSpan {
+            start,
+            end: self.position,
         };
-        self.kind = SyntaxKind::Number;
+        let text = self.text[span].iter().collect::<String>();
+        self.diagnostics.report_invalid_escape_sequence(span, &text);
     }
 
     fn read_whitespace(&mut self) {
@@ -182,8 +542,43 @@ impl Lexer {
         self.value = None;
     }
 
+    fn read_single_line_comment(&mut self) {
+        // skip the '//'
+        self.position += 2;
+        while !matches!(self.current(), '\0' | '\r' | '\n') {
+            self.next();
+        }
+        self.kind = SyntaxKind::SingleLineComment;
+    }
+
+    fn read_multi_line_comment(&mut self) {
+        // skip the '/*'
+        self.position += 2;
+        let mut depth = 1;
+        while depth > 0 {
+            match self.current() {
+                '\0' => {
+                    self.diagnostics
+                        .report_unterminated_multi_line_comment(self.start);
+                    self.unexpected_end_of_file = true;
+                    break;
+                }
+                '/' if self.lookahead() == '*' => {
+                    depth += 1;
+                    self.position += 2;
+                }
+                '*' if self.lookahead() == '/' => {
+                    depth -= 1;
+                    self.position += 2;
+                }
+                _ => self.next(),
+            }
+        }
+        self.kind = SyntaxKind::MultiLineComment;
+    }
+
     fn read_identifier_or_keyword(&mut self) {
-        while self.current().is_alphabetic() {
+        while self.current().is_alphanumeric() {
             self.next();
         }
         let text = self.text[TextSpan {
@@ -214,10 +609,10 @@ mod tests {
         asserting!("tokens length").that(&tokens).has_length(1);
         asserting!("token kind")
             .that(&tokens[0].kind)
-            .is_equal_to(&kind);
+            .is_equal_to(kind);
         asserting!("token text")
             .that(&tokens[0].text.as_str())
-            .is_equal_to(&text);
+            .is_equal_to(text);
     }
 
     fn lex_token_pair(t1kind: SyntaxKind, t1text: &str, t2kind: SyntaxKind, t2text: &str) {
@@ -230,13 +625,13 @@ mod tests {
             .is_equal_to(t1kind);
         asserting!("token 1 text")
             .that(&tokens[0].text.as_str())
-            .is_equal_to(&t1text);
+            .is_equal_to(t1text);
         asserting!("token 2 kind")
             .that(&tokens[1].kind)
             .is_equal_to(t2kind);
         asserting!("token 2 text")
             .that(&tokens[1].text.as_str())
-            .is_equal_to(&t2text);
+            .is_equal_to(t2text);
     }
 
     fn lex_token_pair_with_separator(
@@ -252,34 +647,51 @@ mod tests {
         asserting!("tokens length").that(&tokens).has_length(3);
         asserting!("token 1 kind")
             .that(&tokens[0].kind)
-            .is_equal_to(&t1kind);
+            .is_equal_to(t1kind);
         asserting!("token 1 text")
             .that(&tokens[0].text.as_str())
-            .is_equal_to(&t1text);
+            .is_equal_to(t1text);
         asserting!("separator kind")
             .that(&tokens[1].kind)
-            .is_equal_to(&separator_kind);
+            .is_equal_to(separator_kind);
         asserting!("separator text")
             .that(&tokens[1].text.as_str())
-            .is_equal_to(&separator_text);
+            .is_equal_to(separator_text);
         asserting!("token 2 kind")
             .that(&tokens[2].kind)
-            .is_equal_to(&t2kind);
+            .is_equal_to(t2kind);
         asserting!("token 2 text")
             .that(&tokens[2].text.as_str())
-            .is_equal_to(&t2text);
+            .is_equal_to(t2text);
     }
 
     fn get_tokens() -> Vec<(SyntaxKind, &'static str)> {
         let fixed_tokens = SyntaxKind::iter()
             .map(|k| (k, SyntaxFacts::get_text(k)))
-            .filter_map(|(k, t)| if let Some(t) = t { Some((k, t)) } else { None })
+            .filter_map(|(k, t)| t.map(|t| (k, t)))
             .collect::<Vec<_>>();
         let dynamic_tokens = vec![
             (SyntaxKind::Identifier, "a"),
             (SyntaxKind::Identifier, "abc"),
+            (SyntaxKind::Identifier, "a1"),
             (SyntaxKind::Number, "1"),
             (SyntaxKind::Number, "123"),
+            (SyntaxKind::Number, "1.5"),
+            (SyntaxKind::Number, "2e10"),
+            (SyntaxKind::Number, "0.25E-3"),
+            (SyntaxKind::Number, "1_000"),
+            (SyntaxKind::Number, "0x1F"),
+            (SyntaxKind::Number, "0b1010"),
+            (SyntaxKind::Number, "0o17"),
+            (SyntaxKind::String, "\"Test\""),
+            (SyntaxKind::String, "\"Te\\\"st\""),
+            (SyntaxKind::String, "\"\\u{1F600}\\n\""),
+            (SyntaxKind::Char, "'a'"),
+            (SyntaxKind::Char, "'\\''"),
+            (SyntaxKind::Char, "'\\u{1F600}'"),
+            (SyntaxKind::SingleLineComment, "// comment"),
+            (SyntaxKind::MultiLineComment, "/* comment */"),
+            (SyntaxKind::MultiLineComment, "/* outer /* inner */ */"),
         ];
         fixed_tokens.iter().cloned().chain(dynamic_tokens).collect()
     }
@@ -291,6 +703,7 @@ mod tests {
             (SyntaxKind::Whitespace, "\r"),
             (SyntaxKind::Whitespace, "\n"),
             (SyntaxKind::Whitespace, "\r\n"),
+            (SyntaxKind::MultiLineComment, "/**/"),
         ]
     }
 
@@ -314,8 +727,13 @@ mod tests {
         let mut token_pairs_with_separators = Vec::new();
         for (t1kind, t1text) in get_tokens() {
             for (t2kind, t2text) in get_tokens() {
-                if requires_separator(t1kind, t2kind) {
+                if requires_separator(t1kind, t1text, t2kind, t2text) {
                     for (separator_kind, separator_text) in get_separators() {
+                        if requires_separator(t1kind, t1text, separator_kind, separator_text)
+                            || requires_separator(separator_kind, separator_text, t2kind, t2text)
+                        {
+                            continue;
+                        }
                         token_pairs_with_separators.push((
                             t1kind,
                             t1text,
@@ -331,16 +749,56 @@ mod tests {
         token_pairs_with_separators
     }
 
-    fn requires_separator(t1kind: SyntaxKind, t2kind: SyntaxKind) -> bool {
+    fn requires_separator(
+        t1kind: SyntaxKind,
+        t1text: &str,
+        t2kind: SyntaxKind,
+        t2text: &str,
+    ) -> bool {
         let t1_is_keyword = t1kind.to_string().ends_with("Keyword");
         let t2_is_keyword = t2kind.to_string().ends_with("Keyword");
 
-        t1kind == SyntaxKind::Identifier && t2kind == SyntaxKind::Identifier
-            || t1_is_keyword && t2_is_keyword
-            || t1_is_keyword && t2kind == SyntaxKind::Identifier
-            || t2_is_keyword && t1kind == SyntaxKind::Identifier
+        (t1_is_keyword || t1kind == SyntaxKind::Identifier)
+            && (t2_is_keyword || t2kind == SyntaxKind::Identifier || t2kind == SyntaxKind::Number)
             || t1kind == SyntaxKind::Whitespace && t2kind == SyntaxKind::Whitespace
+            || t1kind == SyntaxKind::SingleLineComment
+            || t1kind == SyntaxKind::Slash
+                && matches!(
+                    t2kind,
+                    SyntaxKind::Slash
+                        | SyntaxKind::Star
+                        | SyntaxKind::SingleLineComment
+                        | SyntaxKind::MultiLineComment
+                        | SyntaxKind::Equals
+                        | SyntaxKind::EqualsEquals
+                        | SyntaxKind::SlashEquals
+                        | SyntaxKind::StarEquals
+                )
+            || t1kind == SyntaxKind::Plus
+                && matches!(
+                    t2kind,
+                    SyntaxKind::Plus
+                        | SyntaxKind::PlusPlus
+                        | SyntaxKind::PlusEquals
+                        | SyntaxKind::Equals
+                        | SyntaxKind::EqualsEquals
+                )
+            || t1kind == SyntaxKind::Minus
+                && matches!(
+                    t2kind,
+                    SyntaxKind::Minus
+                        | SyntaxKind::MinusMinus
+                        | SyntaxKind::MinusEquals
+                        | SyntaxKind::Equals
+                        | SyntaxKind::EqualsEquals
+                )
+            || t1kind == SyntaxKind::Star
+                && matches!(t2kind, SyntaxKind::Equals | SyntaxKind::EqualsEquals)
             || t1kind == SyntaxKind::Number && t2kind == SyntaxKind::Number
+            // a radix literal takes in any letters and digits, so that `0x1Fg` is reported whole
+            || t1kind == SyntaxKind::Number
+                && ["0x", "0b", "0o"].iter().any(|p| t1text.starts_with(p))
+                && t2text.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_')
             || t1kind == SyntaxKind::Bang && t2kind == SyntaxKind::Equals
             || t1kind == SyntaxKind::Equals && t2kind == SyntaxKind::Equals
             || t1kind == SyntaxKind::Equals && t2kind == SyntaxKind::EqualsEquals
@@ -349,6 +807,23 @@ mod tests {
             || t1kind == SyntaxKind::Less && t2kind == SyntaxKind::EqualsEquals
             || t1kind == SyntaxKind::Greater && t2kind == SyntaxKind::Equals
             || t1kind == SyntaxKind::Greater && t2kind == SyntaxKind::EqualsEquals
+            || t1kind == SyntaxKind::Less
+                && matches!(
+                    t2kind,
+                    SyntaxKind::Less | SyntaxKind::LessEquals | SyntaxKind::LessLess
+                )
+            || t1kind == SyntaxKind::Greater
+                && matches!(
+                    t2kind,
+                    SyntaxKind::Greater | SyntaxKind::GreaterEquals | SyntaxKind::GreaterGreater
+                )
+            || t1kind == SyntaxKind::Ampersand
+                && matches!(
+                    t2kind,
+                    SyntaxKind::Ampersand | SyntaxKind::AmpersandAmpersand
+                )
+            || t1kind == SyntaxKind::Pipe
+                && matches!(t2kind, SyntaxKind::Pipe | SyntaxKind::PipePipe)
     }
 
     #[test]
@@ -383,7 +858,9 @@ mod tests {
     #[test]
     fn lexes_token_pairs() {
         for (t1kind, t1text, t2kind, t2text) in get_token_pairs() {
-            if t1kind != SyntaxKind::Whitespace && !requires_separator(t1kind, t2kind) {
+            if t1kind != SyntaxKind::Whitespace
+                && !requires_separator(t1kind, t1text, t2kind, t2text)
+            {
                 lex_token_pair(t1kind, t1text, t2kind, t2text);
             }
         }
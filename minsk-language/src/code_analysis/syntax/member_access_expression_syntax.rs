@@ -0,0 +1,30 @@
+use std::fmt::Display;
+
+use crate::code_analysis::text::text_span::TextSpan;
+
+use super::{expression_syntax::ExpressionSyntax, syntax_token::SyntaxToken};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemberAccessExpressionSyntax {
+    pub(crate) expression: Box<ExpressionSyntax>,
+    pub(crate) dot_token: SyntaxToken,
+    pub(crate) identifier_token: SyntaxToken,
+}
+
+impl MemberAccessExpressionSyntax {
+    pub fn span(&self) -> TextSpan {
+        TextSpan {
+            start: self.expression.span().start,
+            end: self.identifier_token.span.end,
+        }
+    }
+}
+
+impl Display for MemberAccessExpressionSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "MemberAccessExpressionSyntax")?;
+        writeln!(f, "    {}", self.expression)?;
+        writeln!(f, "    {}", self.identifier_token)?;
+        Ok(())
+    }
+}
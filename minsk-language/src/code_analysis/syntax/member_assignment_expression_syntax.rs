@@ -0,0 +1,34 @@
+use std::fmt::Display;
+
+use crate::code_analysis::text::text_span::TextSpan;
+
+use super::{
+    expression_syntax::ExpressionSyntax,
+    member_access_expression_syntax::MemberAccessExpressionSyntax, syntax_token::SyntaxToken,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemberAssignmentExpressionSyntax {
+    pub(crate) target: MemberAccessExpressionSyntax,
+    pub(crate) assignment_token: SyntaxToken,
+    pub(crate) expression: Box<ExpressionSyntax>,
+}
+
+impl MemberAssignmentExpressionSyntax {
+    pub fn span(&self) -> TextSpan {
+        TextSpan {
+            start: self.target.span().start,
+            end: self.expression.span().end,
+        }
+    }
+}
+
+impl Display for MemberAssignmentExpressionSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "MemberAssignmentExpressionSyntax")?;
+        writeln!(f, "    {}", self.target)?;
+        writeln!(f, "    {}", self.assignment_token)?;
+        writeln!(f, "    {}", self.expression)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use std::fmt::Display;
+
+use crate::code_analysis::text::text_span::TextSpan;
+
+use super::{
+    function_declaration_syntax::FunctionDeclarationSyntax,
+    global_statement_syntax::GlobalStatementSyntax,
+    struct_declaration_syntax::StructDeclarationSyntax,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MemberSyntax {
+    Function(Box<FunctionDeclarationSyntax>),
+    GlobalStatement(GlobalStatementSyntax),
+    Struct(Box<StructDeclarationSyntax>),
+}
+
+impl MemberSyntax {
+    pub(crate) fn span(&self) -> TextSpan {
+        match self {
+            MemberSyntax::Function(f) => f.span(),
+            MemberSyntax::GlobalStatement(g) => g.span(),
+            MemberSyntax::Struct(s) => s.span(),
+        }
+    }
+}
+
+impl Display for MemberSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MemberSyntax::Function(d) => write!(f, "{}", d),
+            MemberSyntax::GlobalStatement(g) => write!(f, "{}", g),
+            MemberSyntax::Struct(s) => write!(f, "{}", s),
+        }
+    }
+}
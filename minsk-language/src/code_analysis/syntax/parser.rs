@@ -4,39 +4,76 @@ use crate::code_analysis::{diagnostic_bag::DiagnosticBag, text::source_text::Sou
 
 use super::{
     super::minsk_value::MinskValue,
+    array_expression_syntax::ArrayExpressionSyntax,
+    array_type_syntax::ArrayTypeSyntax,
     assignment_expression_syntax::AssignmentExpressionSyntax,
     block_statement_syntax::BlockStatementSyntax,
+    break_statement_syntax::BreakStatementSyntax,
+    call_expression_syntax::CallExpressionSyntax,
     compilation_unit::CompilationUnit,
+    continue_statement_syntax::ContinueStatementSyntax,
+    do_while_statement_syntax::DoWhileStatementSyntax,
     expression_statement_syntax::ExpressionStatementSyntax,
-    for_statement_syntax::ForStatementSyntax,
+    field_initializer_syntax::FieldInitializerSyntax,
+    field_syntax::FieldSyntax,
+    for_statement_syntax::{ForStatementSyntax, StepClauseSyntax},
+    function_declaration_syntax::FunctionDeclarationSyntax,
+    global_statement_syntax::GlobalStatementSyntax,
     if_statement_syntax::{ElseClauseSyntax, IfStatementSyntax},
+    increment_expression_syntax::IncrementExpressionSyntax,
+    index_assignment_expression_syntax::IndexAssignmentExpressionSyntax,
+    index_expression_syntax::IndexExpressionSyntax,
+    member_access_expression_syntax::MemberAccessExpressionSyntax,
+    member_assignment_expression_syntax::MemberAssignmentExpressionSyntax,
+    member_syntax::MemberSyntax,
     name_expression_syntax::NameExpressionSyntax,
+    parameter_syntax::ParameterSyntax,
+    return_statement_syntax::ReturnStatementSyntax,
+    separated_syntax_list::SeparatedSyntaxList,
     statement_syntax::StatementSyntax,
+    struct_declaration_syntax::StructDeclarationSyntax,
+    struct_expression_syntax::StructExpressionSyntax,
+    type_clause_syntax::TypeClauseSyntax,
+    type_syntax::TypeSyntax,
     variable_declaration_syntax::VariableDeclarationSyntax,
     while_statement_syntax::WhileStatementSyntax,
 };
 
 use super::{
-    binary_expression_syntax::BinaryExpressionSyntax, expression_syntax::ExpressionSyntax,
-    lexer::Lexer, literal_expression_syntax::LiteralExpressionSyntax,
+    binary_expression_syntax::BinaryExpressionSyntax,
+    conditional_expression_syntax::ConditionalExpressionSyntax,
+    expression_syntax::ExpressionSyntax, lexer::Lexer,
+    literal_expression_syntax::LiteralExpressionSyntax,
     parenthesized_expression_syntax::ParenthesizedExpressionSyntax, syntax_facts::SyntaxFactsExt,
-    syntax_kind::SyntaxKind, syntax_token::SyntaxToken, unary_expression_syntax,
+    syntax_kind::SyntaxKind, syntax_token::SyntaxToken, syntax_trivia::SyntaxTrivia,
+    unary_expression_syntax,
 };
 
 pub(super) struct Parser {
+    text: SourceText,
     tokens: Vec<SyntaxToken>,
     position: usize,
+    /// How many parentheses or brackets enclose the current token, inside which a line break
+    /// can't end a statement.
+    delimiter_depth: usize,
+    /// Whether `name { }` is an empty struct literal rather than a name followed by an empty
+    /// block. It's off in the headers of `if`, `while` and `for`, outside of delimiters.
+    struct_literals_allowed: bool,
+    /// Where the statement being parsed starts, whose line's indentation decides whether a
+    /// line break inside it is ambiguous.
+    statement_start: usize,
+    unexpected_end_of_file: bool,
     diagnostics: DiagnosticBag,
 }
 
 impl Parser {
     pub(super) fn new(text: SourceText) -> Self {
-        let mut lexer = Lexer::new(text);
+        let mut lexer = Lexer::new(text.clone());
         let mut tokens = vec![];
         loop {
             let token = lexer.next_token();
             let token_kind = token.kind;
-            if token.kind != SyntaxKind::BadToken && token.kind != SyntaxKind::Whitespace {
+            if token.kind != SyntaxKind::BadToken {
                 tokens.push(token);
             }
             if token_kind == SyntaxKind::EndOfFile {
@@ -44,12 +81,73 @@ impl Parser {
             }
         }
         Self {
-            tokens,
+            text,
+            tokens: Self::attach_trivia(tokens),
             position: 0,
+            delimiter_depth: 0,
+            struct_literals_allowed: true,
+            statement_start: 0,
+            unexpected_end_of_file: lexer.unexpected_end_of_file(),
             diagnostics: lexer.diagnostics(),
         }
     }
 
+    /// Folds whitespace and comments into the surrounding tokens.
+    /// A token's trailing trivia runs up to and including the first line break,
+    /// everything after that leads the next token.
+    fn attach_trivia(raw_tokens: Vec<SyntaxToken>) -> Vec<SyntaxToken> {
+        let mut tokens = vec![];
+        let mut leading_trivia = vec![];
+        let mut raw_tokens = raw_tokens.into_iter().peekable();
+        while let Some(mut token) = raw_tokens.next() {
+            if token.kind.is_trivia() {
+                leading_trivia.push(SyntaxTrivia::new(token.kind, token.position, token.text));
+                continue;
+            }
+
+            token.leading_trivia = std::mem::take(&mut leading_trivia);
+            while let Some(trivia) = raw_tokens.next_if(|t| t.kind.is_trivia()) {
+                if let Some(end) = Self::line_break_end(&trivia) {
+                    let (line, rest) = trivia.text.split_at(end);
+                    token.trailing_trivia.push(SyntaxTrivia::new(
+                        trivia.kind,
+                        trivia.position,
+                        line.to_string(),
+                    ));
+                    if !rest.is_empty() {
+                        leading_trivia.push(SyntaxTrivia::new(
+                            trivia.kind,
+                            trivia.position + line.chars().count(),
+                            rest.to_string(),
+                        ));
+                    }
+                    break;
+                }
+                token.trailing_trivia.push(SyntaxTrivia::new(
+                    trivia.kind,
+                    trivia.position,
+                    trivia.text,
+                ));
+            }
+            tokens.push(token);
+        }
+        tokens
+    }
+
+    /// The byte offset just past the first line break in a whitespace token.
+    fn line_break_end(trivia: &SyntaxToken) -> Option<usize> {
+        if trivia.kind != SyntaxKind::Whitespace {
+            return None;
+        }
+        trivia.text.find(['\r', '\n']).map(|i| {
+            if trivia.text[i..].starts_with("\r\n") {
+                i + 2
+            } else {
+                i + 1
+            }
+        })
+    }
+
     fn peek(&self, offset: usize) -> &SyntaxToken {
         let index = self.position + offset;
         if index >= self.tokens.len() {
@@ -69,10 +167,75 @@ impl Parser {
         current
     }
 
+    /// A statement ends at an optional `;`. Without one, its expression goes on for as long as
+    /// the following tokens can continue it, even across lines.
+    fn parse_optional_semicolon(&mut self) -> Option<SyntaxToken> {
+        if self.current().kind == SyntaxKind::Semicolon {
+            Some(self.next_token())
+        } else {
+            None
+        }
+    }
+
+    /// Reports a token that continues an expression but could just as well start the next
+    /// statement, when it is the first on its line, e.g. the `-` in `a = 1` followed by `-b`.
+    /// Indenting the line past the one the statement starts on makes it a continuation.
+    fn check_continuation(&mut self, offset: usize) {
+        if self.delimiter_depth > 0 || !self.starts_line(offset) {
+            return;
+        }
+        let token = self.peek(offset);
+        if self.indentation(token.span.start) <= self.indentation(self.statement_start) {
+            let (span, text) = (token.span, token.text.clone());
+            self.diagnostics.report_ambiguous_continuation(span, &text);
+        }
+    }
+
+    /// How much whitespace starts the line that `position` is on.
+    fn indentation(&self, position: usize) -> usize {
+        let line = self.text.lines()[self.text.get_line_index(position).unwrap()];
+        self.text[line]
+            .iter()
+            .take_while(|c| c.is_whitespace())
+            .count()
+    }
+
+    /// Whether the token at `offset` is on a later line than the one before it.
+    fn starts_line(&self, offset: usize) -> bool {
+        let index = self.position + offset;
+        if index == 0 || index >= self.tokens.len() {
+            return false;
+        }
+        let (previous, token) = (&self.tokens[index - 1], &self.tokens[index]);
+        self.text.get_line_index(previous.span.start) != self.text.get_line_index(token.span.start)
+    }
+
+    fn parse_delimited<T>(&mut self, parse: impl FnOnce(&mut Self) -> T) -> T {
+        self.delimiter_depth += 1;
+        let result = self.with_struct_literals(true, parse);
+        self.delimiter_depth -= 1;
+        result
+    }
+
+    fn with_struct_literals<T>(&mut self, allowed: bool, parse: impl FnOnce(&mut Self) -> T) -> T {
+        let previous = std::mem::replace(&mut self.struct_literals_allowed, allowed);
+        let result = parse(self);
+        self.struct_literals_allowed = previous;
+        result
+    }
+
+    /// An expression that a statement can directly follow, where `name { }` has to be a block.
+    fn parse_condition(&mut self) -> ExpressionSyntax {
+        self.with_struct_literals(false, Self::parse_expression)
+    }
+
     fn match_token(&mut self, kind: SyntaxKind) -> SyntaxToken {
         if self.current().kind == kind {
             self.next_token()
         } else {
+            if self.current().kind == SyntaxKind::EndOfFile {
+                self.unexpected_end_of_file = true;
+            }
             self.diagnostics.report_unexpected_token(
                 self.current().span,
                 self.current().kind,
@@ -83,22 +246,175 @@ impl Parser {
     }
 
     pub fn parse_compilation_unit(&mut self) -> CompilationUnit {
-        let statement = self.parse_statement();
+        let members = self.parse_members();
         let end_of_file_token = self.match_token(SyntaxKind::EndOfFile);
-        CompilationUnit::new(statement, end_of_file_token)
+        CompilationUnit::new(members, end_of_file_token)
     }
 
-    fn parse_statement(&mut self) -> StatementSyntax {
+    fn parse_members(&mut self) -> Vec<MemberSyntax> {
+        let mut members = Vec::<MemberSyntax>::new();
+
+        while self.current().kind != SyntaxKind::EndOfFile {
+            if self.skip_stray_semicolon() {
+                continue;
+            }
+            let start_token = self.current();
+
+            let member = self.parse_member();
+            members.push(member);
+
+            // same as in parse_block_statement:
+            // skip tokens the member couldn't consume to avoid an infinite loop.
+            if self.peek(0) == &start_token {
+                self.next_token();
+            }
+        }
+
+        members
+    }
+
+    /// A `;` where a statement should start has no statement to end, as in `a = 1;;`.
+    fn skip_stray_semicolon(&mut self) -> bool {
+        if self.current().kind != SyntaxKind::Semicolon {
+            return false;
+        }
+        let semicolon_token = self.next_token();
+        self.diagnostics
+            .report_stray_semicolon(semicolon_token.span);
+        true
+    }
+
+    fn parse_member(&mut self) -> MemberSyntax {
         match self.current().kind {
+            SyntaxKind::FunctionKeyword => {
+                MemberSyntax::Function(Box::new(self.parse_function_declaration()))
+            }
+            SyntaxKind::StructKeyword => {
+                MemberSyntax::Struct(Box::new(self.parse_struct_declaration()))
+            }
+            _ => MemberSyntax::GlobalStatement(GlobalStatementSyntax::new(self.parse_statement())),
+        }
+    }
+
+    fn parse_struct_declaration(&mut self) -> StructDeclarationSyntax {
+        let struct_keyword = self.match_token(SyntaxKind::StructKeyword);
+        let identifier = self.match_token(SyntaxKind::Identifier);
+        let open_brace_token = self.match_token(SyntaxKind::OpenBrace);
+        let fields = self.parse_separated_list(SyntaxKind::CloseBrace, Self::parse_field);
+        let close_brace_token = self.match_token(SyntaxKind::CloseBrace);
+        StructDeclarationSyntax::new(
+            struct_keyword,
+            identifier,
+            open_brace_token,
+            fields,
+            close_brace_token,
+        )
+    }
+
+    fn parse_field(&mut self) -> FieldSyntax {
+        let identifier = self.match_token(SyntaxKind::Identifier);
+        let type_clause = self.parse_type_clause();
+        FieldSyntax::new(identifier, type_clause)
+    }
+
+    fn parse_function_declaration(&mut self) -> FunctionDeclarationSyntax {
+        let function_keyword = self.match_token(SyntaxKind::FunctionKeyword);
+        let identifier = self.match_token(SyntaxKind::Identifier);
+        let open_parenthesis_token = self.match_token(SyntaxKind::OpenParenthesis);
+        let parameters =
+            self.parse_separated_list(SyntaxKind::CloseParenthesis, Self::parse_parameter);
+        let close_parenthesis_token = self.match_token(SyntaxKind::CloseParenthesis);
+        let type_clause = self.parse_optional_type_clause();
+        let body = self.parse_block_statement();
+        FunctionDeclarationSyntax::new(
+            function_keyword,
+            identifier,
+            open_parenthesis_token,
+            parameters,
+            close_parenthesis_token,
+            type_clause,
+            body,
+        )
+    }
+
+    fn parse_parameter(&mut self) -> ParameterSyntax {
+        let identifier = self.match_token(SyntaxKind::Identifier);
+        let type_clause = self.parse_type_clause();
+        ParameterSyntax::new(identifier, type_clause)
+    }
+
+    fn parse_optional_type_clause(&mut self) -> Option<TypeClauseSyntax> {
+        if self.current().kind != SyntaxKind::Colon {
+            return None;
+        }
+
+        Some(self.parse_type_clause())
+    }
+
+    fn parse_type_clause(&mut self) -> TypeClauseSyntax {
+        let colon_token = self.match_token(SyntaxKind::Colon);
+        let ty = self.parse_type();
+        TypeClauseSyntax::new(colon_token, ty)
+    }
+
+    fn parse_type(&mut self) -> TypeSyntax {
+        if self.current().kind != SyntaxKind::OpenBracket {
+            return TypeSyntax::Name(self.match_token(SyntaxKind::Identifier));
+        }
+
+        let open_bracket_token = self.next_token();
+        let element_type = self.parse_type();
+        let close_bracket_token = self.match_token(SyntaxKind::CloseBracket);
+        TypeSyntax::Array(ArrayTypeSyntax::new(
+            open_bracket_token,
+            element_type,
+            close_bracket_token,
+        ))
+    }
+
+    /// Parses a comma-separated list of nodes up to (but not including) the closing token.
+    fn parse_separated_list<T>(
+        &mut self,
+        close_kind: SyntaxKind,
+        parse_node: fn(&mut Self) -> T,
+    ) -> SeparatedSyntaxList<T> {
+        let mut nodes = Vec::<T>::new();
+        let mut separators = Vec::<SyntaxToken>::new();
+
+        while self.current().kind != close_kind && self.current().kind != SyntaxKind::EndOfFile {
+            nodes.push(self.parse_delimited(parse_node));
+
+            if self.current().kind == SyntaxKind::Comma {
+                separators.push(self.match_token(SyntaxKind::Comma));
+            } else {
+                break;
+            }
+        }
+
+        SeparatedSyntaxList::new(nodes, separators)
+    }
+
+    fn parse_statement(&mut self) -> StatementSyntax {
+        let start = self.current().span.start;
+        let outer_statement_start = std::mem::replace(&mut self.statement_start, start);
+        let statement = match self.current().kind {
             SyntaxKind::OpenBrace => StatementSyntax::Block(self.parse_block_statement()),
             SyntaxKind::LetKeyword | SyntaxKind::VarKeyword => {
-                StatementSyntax::VariableDeclaration(self.parse_variable_declaration())
+                StatementSyntax::VariableDeclaration(Box::new(self.parse_variable_declaration()))
             }
             SyntaxKind::ForKeyword => StatementSyntax::For(self.parse_for_statement()),
             SyntaxKind::IfKeyword => StatementSyntax::If(self.parse_if_statement()),
             SyntaxKind::WhileKeyword => StatementSyntax::While(self.parse_while_statement()),
+            SyntaxKind::DoKeyword => StatementSyntax::DoWhile(self.parse_do_while_statement()),
+            SyntaxKind::ReturnKeyword => StatementSyntax::Return(self.parse_return_statement()),
+            SyntaxKind::BreakKeyword => StatementSyntax::Break(self.parse_break_statement()),
+            SyntaxKind::ContinueKeyword => {
+                StatementSyntax::Continue(self.parse_continue_statement())
+            }
             _ => StatementSyntax::Expression(self.parse_expression_statement()),
-        }
+        };
+        self.statement_start = outer_statement_start;
+        statement
     }
 
     fn parse_for_statement(&mut self) -> ForStatementSyntax {
@@ -107,7 +423,8 @@ impl Parser {
         let equals_token = self.match_token(SyntaxKind::Equals);
         let lower_bound = self.parse_expression();
         let to_token = self.match_token(SyntaxKind::ToKeyword);
-        let upper_bound = self.parse_expression();
+        let upper_bound = self.parse_condition();
+        let step_clause = self.parse_step_clause();
         let body = self.parse_statement();
         ForStatementSyntax::new(
             keyword,
@@ -116,20 +433,45 @@ impl Parser {
             Box::new(lower_bound),
             to_token,
             Box::new(upper_bound),
+            step_clause,
             Box::new(body),
         )
     }
 
+    fn parse_step_clause(&mut self) -> Option<StepClauseSyntax> {
+        if self.current().kind != SyntaxKind::StepKeyword {
+            return None;
+        }
+        let keyword = self.next_token();
+        let step = self.parse_condition();
+        Some(StepClauseSyntax::new(keyword, Box::new(step)))
+    }
+
     fn parse_while_statement(&mut self) -> WhileStatementSyntax {
         let keyword = self.match_token(SyntaxKind::WhileKeyword);
-        let condition = self.parse_expression();
+        let condition = self.parse_condition();
         let body = self.parse_statement();
         WhileStatementSyntax::new(keyword, condition, Box::new(body))
     }
 
+    fn parse_do_while_statement(&mut self) -> DoWhileStatementSyntax {
+        let do_keyword = self.match_token(SyntaxKind::DoKeyword);
+        let body = self.parse_statement();
+        let while_keyword = self.match_token(SyntaxKind::WhileKeyword);
+        let condition = self.parse_condition();
+        let semicolon_token = self.parse_optional_semicolon();
+        DoWhileStatementSyntax::new(
+            do_keyword,
+            Box::new(body),
+            while_keyword,
+            condition,
+            semicolon_token,
+        )
+    }
+
     fn parse_if_statement(&mut self) -> IfStatementSyntax {
         let keyword = self.match_token(SyntaxKind::IfKeyword);
-        let condition = self.parse_expression();
+        let condition = self.parse_condition();
         let statement = self.parse_statement();
         let else_clause = self.parse_optional_else_clause();
         IfStatementSyntax::new(keyword, condition, Box::new(statement), else_clause)
@@ -145,6 +487,35 @@ impl Parser {
         Some(ElseClauseSyntax::new(keyword, Box::new(statement)))
     }
 
+    fn parse_break_statement(&mut self) -> BreakStatementSyntax {
+        let keyword = self.match_token(SyntaxKind::BreakKeyword);
+        BreakStatementSyntax::new(keyword, self.parse_optional_semicolon())
+    }
+
+    fn parse_continue_statement(&mut self) -> ContinueStatementSyntax {
+        let keyword = self.match_token(SyntaxKind::ContinueKeyword);
+        ContinueStatementSyntax::new(keyword, self.parse_optional_semicolon())
+    }
+
+    fn parse_return_statement(&mut self) -> ReturnStatementSyntax {
+        let keyword = self.match_token(SyntaxKind::ReturnKeyword);
+        // a return value has to start on the same line, so that a bare `return` doesn't
+        // need a terminator
+        let keyword_line = self.text.get_line_index(keyword.span.start);
+        let current_line = self.text.get_line_index(self.current().span.start);
+        let has_expression = keyword_line == current_line
+            && !matches!(
+                self.current().kind,
+                SyntaxKind::EndOfFile | SyntaxKind::CloseBrace | SyntaxKind::Semicolon
+            );
+        let expression = if has_expression {
+            Some(self.parse_expression())
+        } else {
+            None
+        };
+        ReturnStatementSyntax::new(keyword, expression, self.parse_optional_semicolon())
+    }
+
     fn parse_variable_declaration(&mut self) -> VariableDeclarationSyntax {
         let expected = if self.current().kind == SyntaxKind::LetKeyword {
             SyntaxKind::LetKeyword
@@ -153,9 +524,18 @@ impl Parser {
         };
         let keyword = self.match_token(expected);
         let identifier = self.match_token(SyntaxKind::Identifier);
+        let type_clause = self.parse_optional_type_clause();
         let equals = self.match_token(SyntaxKind::Equals);
         let initializer = self.parse_expression();
-        VariableDeclarationSyntax::new(keyword, identifier, equals, initializer)
+        let semicolon_token = self.parse_optional_semicolon();
+        VariableDeclarationSyntax::new(
+            keyword,
+            identifier,
+            type_clause,
+            equals,
+            initializer,
+            semicolon_token,
+        )
     }
 
     fn parse_block_statement(&mut self) -> BlockStatementSyntax {
@@ -165,6 +545,9 @@ impl Parser {
         while self.current().kind != SyntaxKind::EndOfFile
             && self.current().kind != SyntaxKind::CloseBrace
         {
+            if self.skip_stray_semicolon() {
+                continue;
+            }
             let start_token = self.current();
 
             let statement = self.parse_statement();
@@ -188,7 +571,7 @@ impl Parser {
 
     fn parse_expression_statement(&mut self) -> ExpressionStatementSyntax {
         let expression = self.parse_expression();
-        ExpressionStatementSyntax::new(expression)
+        ExpressionStatementSyntax::new(expression, self.parse_optional_semicolon())
     }
 
     fn parse_expression(&mut self) -> ExpressionSyntax {
@@ -196,17 +579,53 @@ impl Parser {
     }
 
     fn parse_assignment_expression(&mut self) -> ExpressionSyntax {
-        if self.peek(0).kind == SyntaxKind::Identifier && self.peek(1).kind == SyntaxKind::Equals {
+        let is_assignment = Self::is_assignment_operator(self.peek(1).kind);
+        if self.peek(0).kind == SyntaxKind::Identifier && is_assignment {
             let identifier_token = self.next_token();
-            let operator_token = self.next_token();
+            let assignment_token = self.next_token();
             let right = self.parse_assignment_expression();
             return ExpressionSyntax::Assignment(AssignmentExpressionSyntax {
                 identifier_token,
-                equals_token: operator_token,
+                assignment_token,
                 expression: Box::new(right),
             });
         }
-        self.parse_binary_expression(0)
+        match self.parse_binary_expression(0) {
+            ExpressionSyntax::Index(target)
+                if Self::is_assignment_operator(self.current().kind) =>
+            {
+                let assignment_token = self.next_token();
+                let right = self.parse_assignment_expression();
+                ExpressionSyntax::IndexAssignment(IndexAssignmentExpressionSyntax {
+                    target,
+                    assignment_token,
+                    expression: Box::new(right),
+                })
+            }
+            ExpressionSyntax::MemberAccess(target)
+                if Self::is_assignment_operator(self.current().kind) =>
+            {
+                let assignment_token = self.next_token();
+                let right = self.parse_assignment_expression();
+                ExpressionSyntax::MemberAssignment(MemberAssignmentExpressionSyntax {
+                    target,
+                    assignment_token,
+                    expression: Box::new(right),
+                })
+            }
+            expression => expression,
+        }
+    }
+
+    fn is_assignment_operator(kind: SyntaxKind) -> bool {
+        matches!(
+            kind,
+            SyntaxKind::Equals
+                | SyntaxKind::PlusEquals
+                | SyntaxKind::MinusEquals
+                | SyntaxKind::StarEquals
+                | SyntaxKind::SlashEquals
+        )
     }
 
     fn parse_binary_expression(&mut self, parent_precedence: usize) -> ExpressionSyntax {
@@ -224,10 +643,19 @@ impl Parser {
             };
 
         loop {
+            let precedence = self.current().kind.conditional_operator_precedence();
+            if precedence != 0 && precedence > parent_precedence {
+                left = self.parse_conditional_expression(left, precedence);
+                continue;
+            }
+
             let precedence = self.current().kind.binary_operator_precedence();
             if precedence == 0 || precedence <= parent_precedence {
                 break;
             }
+            if self.current().kind.unary_operator_precedence() != 0 {
+                self.check_continuation(0);
+            }
 
             let operator_token = self.next_token();
             let right = self.parse_binary_expression(precedence);
@@ -241,18 +669,157 @@ impl Parser {
         left
     }
 
+    fn parse_conditional_expression(
+        &mut self,
+        condition: ExpressionSyntax,
+        precedence: usize,
+    ) -> ExpressionSyntax {
+        let question_token = self.match_token(SyntaxKind::QuestionMark);
+        let when_true = self.parse_expression();
+        let colon_token = self.match_token(SyntaxKind::Colon);
+        // right associative, so `a ? b : c ? d : e` is `a ? b : (c ? d : e)`
+        let when_false = self.parse_binary_expression(precedence - 1);
+        ExpressionSyntax::Conditional(ConditionalExpressionSyntax {
+            condition: Box::new(condition),
+            question_token,
+            when_true: Box::new(when_true),
+            colon_token,
+            when_false: Box::new(when_false),
+        })
+    }
+
     fn parse_primary_expression(&mut self) -> ExpressionSyntax {
-        match self.current().kind {
+        let mut expression = match self.current().kind {
             SyntaxKind::OpenParenthesis => self.parse_parenthesized_expression(),
+            SyntaxKind::OpenBracket => self.parse_array_expression(),
             SyntaxKind::TrueKeyword | SyntaxKind::FalseKeyword => self.parse_boolean_expression(),
             SyntaxKind::Number => self.parse_numeric_literal(),
+            SyntaxKind::String => self.parse_string_literal(),
+            SyntaxKind::Char => self.parse_char_literal(),
+            SyntaxKind::Identifier if self.peek(1).kind == SyntaxKind::OpenParenthesis => {
+                self.check_continuation(1);
+                self.parse_call_expression()
+            }
+            // `name {` alone could also start a block, e.g. in `if x { ... }`
+            SyntaxKind::Identifier
+                if self.peek(1).kind == SyntaxKind::OpenBrace
+                    && self.peek(2).kind == SyntaxKind::Identifier
+                    && self.peek(3).kind == SyntaxKind::Colon =>
+            {
+                self.parse_struct_expression()
+            }
+            // `name { }` is only an empty block where a statement can follow the name,
+            // or when the block starts a line of its own
+            SyntaxKind::Identifier
+                if self.peek(1).kind == SyntaxKind::OpenBrace
+                    && self.peek(2).kind == SyntaxKind::CloseBrace
+                    && self.struct_literals_allowed
+                    && !self.starts_line(1) =>
+            {
+                self.parse_struct_expression()
+            }
+            SyntaxKind::Identifier
+                if matches!(
+                    self.peek(1).kind,
+                    SyntaxKind::PlusPlus | SyntaxKind::MinusMinus
+                ) =>
+            {
+                self.check_continuation(1);
+                self.parse_postfix_increment_expression()
+            }
+            SyntaxKind::PlusPlus | SyntaxKind::MinusMinus => {
+                self.parse_prefix_increment_expression()
+            }
             _ => self.parse_name_expression(),
+        };
+        loop {
+            expression = match self.current().kind {
+                SyntaxKind::OpenBracket => {
+                    self.check_continuation(0);
+                    let open_bracket_token = self.next_token();
+                    let index = self.parse_delimited(Self::parse_expression);
+                    let close_bracket_token = self.match_token(SyntaxKind::CloseBracket);
+                    ExpressionSyntax::Index(IndexExpressionSyntax {
+                        expression: Box::new(expression),
+                        open_bracket_token,
+                        index: Box::new(index),
+                        close_bracket_token,
+                    })
+                }
+                SyntaxKind::Dot => {
+                    let dot_token = self.next_token();
+                    let identifier_token = self.match_token(SyntaxKind::Identifier);
+                    ExpressionSyntax::MemberAccess(MemberAccessExpressionSyntax {
+                        expression: Box::new(expression),
+                        dot_token,
+                        identifier_token,
+                    })
+                }
+                _ => return expression,
+            };
+        }
+    }
+
+    fn parse_struct_expression(&mut self) -> ExpressionSyntax {
+        let identifier_token = self.match_token(SyntaxKind::Identifier);
+        let open_brace_token = self.match_token(SyntaxKind::OpenBrace);
+        let fields = self.with_struct_literals(true, |parser| {
+            parser.parse_separated_list(SyntaxKind::CloseBrace, Self::parse_field_initializer)
+        });
+        let close_brace_token = self.match_token(SyntaxKind::CloseBrace);
+        ExpressionSyntax::Struct(StructExpressionSyntax {
+            identifier_token,
+            open_brace_token,
+            fields,
+            close_brace_token,
+        })
+    }
+
+    fn parse_field_initializer(&mut self) -> FieldInitializerSyntax {
+        let identifier_token = self.match_token(SyntaxKind::Identifier);
+        let colon_token = self.match_token(SyntaxKind::Colon);
+        let expression = self.parse_expression();
+        FieldInitializerSyntax {
+            identifier_token,
+            colon_token,
+            expression,
         }
     }
 
+    fn parse_array_expression(&mut self) -> ExpressionSyntax {
+        let open_bracket_token = self.match_token(SyntaxKind::OpenBracket);
+        let elements = self.parse_separated_list(SyntaxKind::CloseBracket, Self::parse_expression);
+        let close_bracket_token = self.match_token(SyntaxKind::CloseBracket);
+        ExpressionSyntax::Array(ArrayExpressionSyntax {
+            open_bracket_token,
+            elements,
+            close_bracket_token,
+        })
+    }
+
+    fn parse_prefix_increment_expression(&mut self) -> ExpressionSyntax {
+        let operator_token = self.next_token();
+        let identifier_token = self.match_token(SyntaxKind::Identifier);
+        ExpressionSyntax::Increment(IncrementExpressionSyntax {
+            identifier_token,
+            operator_token,
+            is_postfix: false,
+        })
+    }
+
+    fn parse_postfix_increment_expression(&mut self) -> ExpressionSyntax {
+        let identifier_token = self.next_token();
+        let operator_token = self.next_token();
+        ExpressionSyntax::Increment(IncrementExpressionSyntax {
+            identifier_token,
+            operator_token,
+            is_postfix: true,
+        })
+    }
+
     fn parse_parenthesized_expression(&mut self) -> ExpressionSyntax {
         let open_parenthesis_token = self.next_token();
-        let expression = self.parse_expression();
+        let expression = self.parse_delimited(Self::parse_expression);
         let close_parenthesis_token = self.match_token(SyntaxKind::CloseParenthesis);
         ExpressionSyntax::Parenthesized(ParenthesizedExpressionSyntax {
             open_parenthesis_token,
@@ -275,6 +842,20 @@ impl Parser {
         })
     }
 
+    fn parse_call_expression(&mut self) -> ExpressionSyntax {
+        let identifier_token = self.match_token(SyntaxKind::Identifier);
+        let open_parenthesis_token = self.match_token(SyntaxKind::OpenParenthesis);
+        let arguments =
+            self.parse_separated_list(SyntaxKind::CloseParenthesis, Self::parse_expression);
+        let close_parenthesis_token = self.match_token(SyntaxKind::CloseParenthesis);
+        ExpressionSyntax::Call(CallExpressionSyntax {
+            identifier_token,
+            open_parenthesis_token,
+            arguments,
+            close_parenthesis_token,
+        })
+    }
+
     fn parse_name_expression(&mut self) -> ExpressionSyntax {
         let identifier_token = self.match_token(SyntaxKind::Identifier);
         ExpressionSyntax::Name(NameExpressionSyntax { identifier_token })
@@ -285,6 +866,24 @@ impl Parser {
         ExpressionSyntax::Literal(LiteralExpressionSyntax::new(literal_token))
     }
 
+    fn parse_string_literal(&mut self) -> ExpressionSyntax {
+        let literal_token = self.match_token(SyntaxKind::String);
+        ExpressionSyntax::Literal(LiteralExpressionSyntax::new(literal_token))
+    }
+
+    fn parse_char_literal(&mut self) -> ExpressionSyntax {
+        let literal_token = self.match_token(SyntaxKind::Char);
+        ExpressionSyntax::Literal(LiteralExpressionSyntax::new(literal_token))
+    }
+
+    /// Whether the text is a finished submission, even if it has errors. Text that ends before
+    /// its last statement does is incomplete, unless that statement is terminated with `;`.
+    pub(super) fn is_complete(&self) -> bool {
+        let terminated = self.tokens.len() >= 2
+            && self.tokens[self.tokens.len() - 2].kind == SyntaxKind::Semicolon;
+        !self.unexpected_end_of_file || terminated
+    }
+
     pub fn diagnostics(self) -> DiagnosticBag {
         self.diagnostics
     }
@@ -304,6 +903,79 @@ mod tests {
     use strum::IntoEnumIterator;
     use syntax_facts::{SyntaxFacts, SyntaxFactsExt};
 
+    fn parse_statement(text: String) -> StatementSyntax {
+        let tree = SyntaxTree::parse(text);
+        let members = tree.root().members();
+        assert_eq!(members.len(), 1);
+        match &members[0] {
+            MemberSyntax::GlobalStatement(g) => g.statement().clone(),
+            m => panic!("expected a global statement, got {:?}", m),
+        }
+    }
+
+    fn trivia_texts(trivia: &[SyntaxTrivia]) -> Vec<(SyntaxKind, &str)> {
+        trivia.iter().map(|t| (t.kind, t.text.as_str())).collect()
+    }
+
+    #[test]
+    fn attaches_trivia_to_tokens() {
+        let parser = Parser::new(SourceText::from(
+            "// lead\n1 /* mid */ +\n  2 // tail\n".to_string(),
+        ));
+        let tokens = &parser.tokens;
+
+        asserting!("tokens length").that(tokens).has_length(4);
+        asserting!("leading trivia of 1")
+            .that(&trivia_texts(&tokens[0].leading_trivia))
+            .is_equal_to(vec![
+                (SyntaxKind::SingleLineComment, "// lead"),
+                (SyntaxKind::Whitespace, "\n"),
+            ]);
+        asserting!("trailing trivia of 1")
+            .that(&trivia_texts(&tokens[0].trailing_trivia))
+            .is_equal_to(vec![
+                (SyntaxKind::Whitespace, " "),
+                (SyntaxKind::MultiLineComment, "/* mid */"),
+                (SyntaxKind::Whitespace, " "),
+            ]);
+        asserting!("trailing trivia of +")
+            .that(&trivia_texts(&tokens[1].trailing_trivia))
+            .is_equal_to(vec![(SyntaxKind::Whitespace, "\n")]);
+        asserting!("leading trivia of 2")
+            .that(&trivia_texts(&tokens[2].leading_trivia))
+            .is_equal_to(vec![(SyntaxKind::Whitespace, "  ")]);
+        asserting!("trailing trivia of 2")
+            .that(&trivia_texts(&tokens[2].trailing_trivia))
+            .is_equal_to(vec![
+                (SyntaxKind::Whitespace, " "),
+                (SyntaxKind::SingleLineComment, "// tail"),
+                (SyntaxKind::Whitespace, "\n"),
+            ]);
+        asserting!("trivia of end of file")
+            .that(&tokens[3].leading_trivia)
+            .is_empty();
+    }
+
+    #[test]
+    fn trivia_round_trips_source_text() {
+        let text = "{\r\n    var x = 1 /* a\n b */ // c\n\n    x\n}\n";
+        let parser = Parser::new(SourceText::from(text.to_string()));
+        let round_tripped = parser
+            .tokens
+            .iter()
+            .flat_map(|t| {
+                t.leading_trivia
+                    .iter()
+                    .map(|t| t.text.as_str())
+                    .chain(std::iter::once(t.text.as_str()))
+                    .chain(t.trailing_trivia.iter().map(|t| t.text.as_str()))
+            })
+            .collect::<String>();
+        asserting!("round-tripped text")
+            .that(&round_tripped.as_str())
+            .is_equal_to(text);
+    }
+
     fn unary_expression_honors_precedences_helper(unary_kind: SyntaxKind, binary_kind: SyntaxKind) {
         let op1_precedence = unary_kind.unary_operator_precedence();
         let op2_precedence = binary_kind.binary_operator_precedence();
@@ -314,25 +986,61 @@ mod tests {
 
         if op1_precedence >= op2_precedence {
             asserting!("syntax tree")
-                .that(&SyntaxTree::parse(text).root().statement())
-                .is_equal_to(&StatementSyntax::Expression(
-                    ExpressionStatementSyntax::new(ExpressionSyntax::Binary(
-                        BinaryExpressionSyntax {
-                            left: Box::new(ExpressionSyntax::Unary(UnaryExpressionSyntax {
-                                operator_token: SyntaxToken::new(
-                                    unary_kind,
-                                    0,
-                                    String::from(op1_text),
+                .that(&parse_statement(text))
+                .is_equal_to(StatementSyntax::Expression(ExpressionStatementSyntax::new(
+                    ExpressionSyntax::Binary(BinaryExpressionSyntax {
+                        left: Box::new(ExpressionSyntax::Unary(UnaryExpressionSyntax {
+                            operator_token: SyntaxToken::new(
+                                unary_kind,
+                                0,
+                                String::from(op1_text),
+                                None,
+                            ),
+                            operand: Box::new(ExpressionSyntax::Name(NameExpressionSyntax {
+                                identifier_token: SyntaxToken::new(
+                                    SyntaxKind::Identifier,
+                                    1,
+                                    String::from("a"),
+                                    None,
+                                ),
+                            })),
+                        })),
+                        operator_token: SyntaxToken::new(
+                            binary_kind,
+                            2,
+                            String::from(op2_text),
+                            None,
+                        ),
+                        right: Box::new(ExpressionSyntax::Name(NameExpressionSyntax {
+                            identifier_token: SyntaxToken::new(
+                                SyntaxKind::Identifier,
+                                2 + op2_text.len(),
+                                String::from("b"),
+                                None,
+                            ),
+                        })),
+                    }),
+                    None,
+                )));
+        } else {
+            asserting!("syntax tree")
+                .that(&parse_statement(text))
+                .is_equal_to(StatementSyntax::Expression(ExpressionStatementSyntax::new(
+                    ExpressionSyntax::Unary(UnaryExpressionSyntax {
+                        operator_token: SyntaxToken::new(
+                            unary_kind,
+                            0,
+                            String::from(op1_text),
+                            None,
+                        ),
+                        operand: Box::new(ExpressionSyntax::Binary(BinaryExpressionSyntax {
+                            left: Box::new(ExpressionSyntax::Name(NameExpressionSyntax {
+                                identifier_token: SyntaxToken::new(
+                                    SyntaxKind::Identifier,
+                                    1,
+                                    String::from("a"),
                                     None,
                                 ),
-                                operand: Box::new(ExpressionSyntax::Name(NameExpressionSyntax {
-                                    identifier_token: SyntaxToken::new(
-                                        SyntaxKind::Identifier,
-                                        1,
-                                        String::from("a"),
-                                        None,
-                                    ),
-                                })),
                             })),
                             operator_token: SyntaxToken::new(
                                 binary_kind,
@@ -348,48 +1056,10 @@ mod tests {
                                     None,
                                 ),
                             })),
-                        },
-                    )),
-                ));
-        } else {
-            asserting!("syntax tree")
-                .that(&SyntaxTree::parse(text).root().statement())
-                .is_equal_to(&StatementSyntax::Expression(
-                    ExpressionStatementSyntax::new(ExpressionSyntax::Unary(
-                        UnaryExpressionSyntax {
-                            operator_token: SyntaxToken::new(
-                                unary_kind,
-                                0,
-                                String::from(op1_text),
-                                None,
-                            ),
-                            operand: Box::new(ExpressionSyntax::Binary(BinaryExpressionSyntax {
-                                left: Box::new(ExpressionSyntax::Name(NameExpressionSyntax {
-                                    identifier_token: SyntaxToken::new(
-                                        SyntaxKind::Identifier,
-                                        1,
-                                        String::from("a"),
-                                        None,
-                                    ),
-                                })),
-                                operator_token: SyntaxToken::new(
-                                    binary_kind,
-                                    2,
-                                    String::from(op2_text),
-                                    None,
-                                ),
-                                right: Box::new(ExpressionSyntax::Name(NameExpressionSyntax {
-                                    identifier_token: SyntaxToken::new(
-                                        SyntaxKind::Identifier,
-                                        2 + op2_text.len(),
-                                        String::from("b"),
-                                        None,
-                                    ),
-                                })),
-                            })),
-                        },
-                    )),
-                ));
+                        })),
+                    }),
+                    None,
+                )));
         }
     }
 
@@ -403,93 +1073,107 @@ mod tests {
 
         if op1_precedence >= op2_precedence {
             asserting!("syntax tree")
-                .that(&SyntaxTree::parse(text).root().statement())
-                .is_equal_to(&StatementSyntax::Expression(
-                    ExpressionStatementSyntax::new(ExpressionSyntax::Binary(
-                        BinaryExpressionSyntax {
-                            left: Box::new(ExpressionSyntax::Binary(BinaryExpressionSyntax {
-                                left: Box::new(ExpressionSyntax::Name(NameExpressionSyntax {
-                                    identifier_token: SyntaxToken::new(
-                                        SyntaxKind::Identifier,
-                                        0,
-                                        String::from("a"),
-                                        None,
-                                    ),
-                                })),
-                                operator_token: SyntaxToken::new(
-                                    op1,
-                                    1,
-                                    String::from(op1_text),
+                .that(&parse_statement(text))
+                .is_equal_to(StatementSyntax::Expression(ExpressionStatementSyntax::new(
+                    ExpressionSyntax::Binary(BinaryExpressionSyntax {
+                        left: Box::new(ExpressionSyntax::Binary(BinaryExpressionSyntax {
+                            left: Box::new(ExpressionSyntax::Name(NameExpressionSyntax {
+                                identifier_token: SyntaxToken::new(
+                                    SyntaxKind::Identifier,
+                                    0,
+                                    String::from("a"),
                                     None,
                                 ),
-                                right: Box::new(ExpressionSyntax::Name(NameExpressionSyntax {
-                                    identifier_token: SyntaxToken::new(
-                                        SyntaxKind::Identifier,
-                                        1 + op1_text.len(),
-                                        String::from("b"),
-                                        None,
-                                    ),
-                                })),
                             })),
-                            operator_token: SyntaxToken::new(
-                                op2,
-                                2 + op1_text.len(),
-                                String::from(op2_text),
-                                None,
-                            ),
+                            operator_token: SyntaxToken::new(op1, 1, String::from(op1_text), None),
                             right: Box::new(ExpressionSyntax::Name(NameExpressionSyntax {
                                 identifier_token: SyntaxToken::new(
                                     SyntaxKind::Identifier,
-                                    2 + op1_text.len() + op2_text.len(),
-                                    String::from("c"),
+                                    1 + op1_text.len(),
+                                    String::from("b"),
                                     None,
                                 ),
                             })),
-                        },
-                    )),
-                ));
+                        })),
+                        operator_token: SyntaxToken::new(
+                            op2,
+                            2 + op1_text.len(),
+                            String::from(op2_text),
+                            None,
+                        ),
+                        right: Box::new(ExpressionSyntax::Name(NameExpressionSyntax {
+                            identifier_token: SyntaxToken::new(
+                                SyntaxKind::Identifier,
+                                2 + op1_text.len() + op2_text.len(),
+                                String::from("c"),
+                                None,
+                            ),
+                        })),
+                    }),
+                    None,
+                )));
         } else {
             asserting!("syntax tree")
-                .that(&SyntaxTree::parse(text).root().statement())
-                .is_equal_to(&StatementSyntax::Expression(
-                    ExpressionStatementSyntax::new(ExpressionSyntax::Binary(
-                        BinaryExpressionSyntax {
+                .that(&parse_statement(text))
+                .is_equal_to(StatementSyntax::Expression(ExpressionStatementSyntax::new(
+                    ExpressionSyntax::Binary(BinaryExpressionSyntax {
+                        left: Box::new(ExpressionSyntax::Name(NameExpressionSyntax {
+                            identifier_token: SyntaxToken::new(
+                                SyntaxKind::Identifier,
+                                0,
+                                String::from("a"),
+                                None,
+                            ),
+                        })),
+                        operator_token: SyntaxToken::new(op1, 1, String::from(op1_text), None),
+                        right: Box::new(ExpressionSyntax::Binary(BinaryExpressionSyntax {
                             left: Box::new(ExpressionSyntax::Name(NameExpressionSyntax {
                                 identifier_token: SyntaxToken::new(
                                     SyntaxKind::Identifier,
-                                    0,
-                                    String::from("a"),
+                                    1 + op1_text.len(),
+                                    String::from("b"),
                                     None,
                                 ),
                             })),
-                            operator_token: SyntaxToken::new(op1, 1, String::from(op1_text), None),
-                            right: Box::new(ExpressionSyntax::Binary(BinaryExpressionSyntax {
-                                left: Box::new(ExpressionSyntax::Name(NameExpressionSyntax {
-                                    identifier_token: SyntaxToken::new(
-                                        SyntaxKind::Identifier,
-                                        1 + op1_text.len(),
-                                        String::from("b"),
-                                        None,
-                                    ),
-                                })),
-                                operator_token: SyntaxToken::new(
-                                    op2,
-                                    2 + op1_text.len(),
-                                    String::from(op2_text),
+                            operator_token: SyntaxToken::new(
+                                op2,
+                                2 + op1_text.len(),
+                                String::from(op2_text),
+                                None,
+                            ),
+                            right: Box::new(ExpressionSyntax::Name(NameExpressionSyntax {
+                                identifier_token: SyntaxToken::new(
+                                    SyntaxKind::Identifier,
+                                    2 + op1_text.len() + op2_text.len(),
+                                    String::from("c"),
                                     None,
                                 ),
-                                right: Box::new(ExpressionSyntax::Name(NameExpressionSyntax {
-                                    identifier_token: SyntaxToken::new(
-                                        SyntaxKind::Identifier,
-                                        2 + op1_text.len() + op2_text.len(),
-                                        String::from("c"),
-                                        None,
-                                    ),
-                                })),
                             })),
-                        },
-                    )),
-                ));
+                        })),
+                    }),
+                    None,
+                )));
+        }
+    }
+
+    #[test]
+    fn reports_completeness() {
+        for (text, complete) in [
+            ("1 + 2", true),
+            ("1 +", false),
+            ("{ var a = 1", false),
+            ("{ var a = 1;", true),
+            ("/* comment", false),
+            ("1 + ;", true),
+            ("1 2 )", true),
+        ]
+        .iter()
+        {
+            let mut parser = Parser::new(SourceText::from(text.to_string()));
+            parser.parse_compilation_unit();
+            asserting!(text)
+                .that(&parser.is_complete())
+                .is_equal_to(complete);
         }
     }
 
@@ -0,0 +1,50 @@
+use std::fmt::Display;
+
+use crate::code_analysis::text::text_span::TextSpan;
+
+use super::{expression_syntax::ExpressionSyntax, syntax_token::SyntaxToken};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReturnStatementSyntax {
+    return_keyword: SyntaxToken,
+    expression: Option<ExpressionSyntax>,
+    semicolon_token: Option<SyntaxToken>,
+}
+
+impl ReturnStatementSyntax {
+    pub(crate) fn new(
+        return_keyword: SyntaxToken,
+        expression: Option<ExpressionSyntax>,
+        semicolon_token: Option<SyntaxToken>,
+    ) -> Self {
+        Self {
+            return_keyword,
+            expression,
+            semicolon_token,
+        }
+    }
+
+    pub(crate) fn span(&self) -> TextSpan {
+        TextSpan {
+            start: self.return_keyword.span.start,
+            end: match &self.expression {
+                Some(e) => e.span().end,
+                None => self.return_keyword.span.end,
+            },
+        }
+    }
+
+    pub(crate) fn expression(&self) -> Option<&ExpressionSyntax> {
+        self.expression.as_ref()
+    }
+}
+
+impl Display for ReturnStatementSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "ReturnStatementSyntax")?;
+        if let Some(e) = &self.expression {
+            writeln!(f, "    {}", e)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use std::{fmt::Display, ops::Index};
+
+use super::syntax_token::SyntaxToken;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeparatedSyntaxList<T> {
+    nodes: Vec<T>,
+    separators: Vec<SyntaxToken>,
+}
+
+impl<T> SeparatedSyntaxList<T> {
+    pub(crate) fn new(nodes: Vec<T>, separators: Vec<SyntaxToken>) -> Self {
+        Self { nodes, separators }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &T> {
+        self.nodes.iter()
+    }
+}
+
+impl<T> Index<usize> for SeparatedSyntaxList<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.nodes[index]
+    }
+}
+
+impl<T: Display> Display for SeparatedSyntaxList<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for node in self.iter() {
+            writeln!(f, "    {}", node)?;
+        }
+        Ok(())
+    }
+}
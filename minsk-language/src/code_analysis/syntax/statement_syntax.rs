@@ -3,9 +3,12 @@ use std::fmt::Display;
 use crate::code_analysis::text::text_span::TextSpan;
 
 use super::{
-    block_statement_syntax::BlockStatementSyntax,
+    block_statement_syntax::BlockStatementSyntax, break_statement_syntax::BreakStatementSyntax,
+    continue_statement_syntax::ContinueStatementSyntax,
+    do_while_statement_syntax::DoWhileStatementSyntax,
     expression_statement_syntax::ExpressionStatementSyntax,
     for_statement_syntax::ForStatementSyntax, if_statement_syntax::IfStatementSyntax,
+    return_statement_syntax::ReturnStatementSyntax,
     variable_declaration_syntax::VariableDeclarationSyntax,
     while_statement_syntax::WhileStatementSyntax,
 };
@@ -13,10 +16,14 @@ use super::{
 #[derive(Debug, Clone, PartialEq)]
 pub enum StatementSyntax {
     Block(BlockStatementSyntax),
+    Break(BreakStatementSyntax),
+    Continue(ContinueStatementSyntax),
+    DoWhile(DoWhileStatementSyntax),
     Expression(ExpressionStatementSyntax),
     For(ForStatementSyntax),
     If(IfStatementSyntax),
-    VariableDeclaration(VariableDeclarationSyntax),
+    Return(ReturnStatementSyntax),
+    VariableDeclaration(Box<VariableDeclarationSyntax>),
     While(WhileStatementSyntax),
 }
 
@@ -24,9 +31,13 @@ impl StatementSyntax {
     pub(crate) fn span(&self) -> TextSpan {
         match self {
             StatementSyntax::Block(b) => b.span(),
+            StatementSyntax::Break(b) => b.span(),
+            StatementSyntax::Continue(c) => c.span(),
+            StatementSyntax::DoWhile(d) => d.span(),
             StatementSyntax::Expression(e) => e.span(),
             StatementSyntax::For(f) => f.span(),
             StatementSyntax::If(i) => i.span(),
+            StatementSyntax::Return(r) => r.span(),
             StatementSyntax::VariableDeclaration(v) => v.span(),
             StatementSyntax::While(w) => w.span(),
         }
@@ -37,9 +48,13 @@ impl Display for StatementSyntax {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             StatementSyntax::Block(b) => write!(f, "{}", b),
+            StatementSyntax::Break(b) => write!(f, "{}", b),
+            StatementSyntax::Continue(c) => write!(f, "{}", c),
+            StatementSyntax::DoWhile(d) => write!(f, "{}", d),
             StatementSyntax::Expression(e) => write!(f, "{}", e),
             StatementSyntax::For(o) => write!(f, "{}", o),
             StatementSyntax::If(i) => write!(f, "{}", i),
+            StatementSyntax::Return(r) => write!(f, "{}", r),
             StatementSyntax::VariableDeclaration(v) => write!(f, "{}", v),
             StatementSyntax::While(w) => write!(f, "{}", w),
         }
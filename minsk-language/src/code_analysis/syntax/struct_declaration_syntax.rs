@@ -0,0 +1,58 @@
+use std::fmt::Display;
+
+use crate::code_analysis::text::text_span::TextSpan;
+
+use super::{
+    field_syntax::FieldSyntax, separated_syntax_list::SeparatedSyntaxList,
+    syntax_token::SyntaxToken,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructDeclarationSyntax {
+    struct_keyword: SyntaxToken,
+    identifier: SyntaxToken,
+    open_brace_token: SyntaxToken,
+    fields: SeparatedSyntaxList<FieldSyntax>,
+    close_brace_token: SyntaxToken,
+}
+
+impl StructDeclarationSyntax {
+    pub(crate) fn new(
+        struct_keyword: SyntaxToken,
+        identifier: SyntaxToken,
+        open_brace_token: SyntaxToken,
+        fields: SeparatedSyntaxList<FieldSyntax>,
+        close_brace_token: SyntaxToken,
+    ) -> Self {
+        Self {
+            struct_keyword,
+            identifier,
+            open_brace_token,
+            fields,
+            close_brace_token,
+        }
+    }
+
+    pub(crate) fn span(&self) -> TextSpan {
+        TextSpan {
+            start: self.struct_keyword.span.start,
+            end: self.close_brace_token.span.end,
+        }
+    }
+
+    pub(crate) fn identifier(&self) -> &SyntaxToken {
+        &self.identifier
+    }
+
+    pub(crate) fn fields(&self) -> &SeparatedSyntaxList<FieldSyntax> {
+        &self.fields
+    }
+}
+
+impl Display for StructDeclarationSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "StructDeclarationSyntax({})", self.identifier.text)?;
+        write!(f, "{}", self.fields)?;
+        Ok(())
+    }
+}
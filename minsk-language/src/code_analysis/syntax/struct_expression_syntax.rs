@@ -0,0 +1,34 @@
+use std::fmt::Display;
+
+use crate::code_analysis::text::text_span::TextSpan;
+
+use super::{
+    field_initializer_syntax::FieldInitializerSyntax, separated_syntax_list::SeparatedSyntaxList,
+    syntax_token::SyntaxToken,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructExpressionSyntax {
+    pub(crate) identifier_token: SyntaxToken,
+    pub(crate) open_brace_token: SyntaxToken,
+    pub(crate) fields: SeparatedSyntaxList<FieldInitializerSyntax>,
+    pub(crate) close_brace_token: SyntaxToken,
+}
+
+impl StructExpressionSyntax {
+    pub fn span(&self) -> TextSpan {
+        TextSpan {
+            start: self.identifier_token.span.start,
+            end: self.close_brace_token.span.end,
+        }
+    }
+}
+
+impl Display for StructExpressionSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "StructExpressionSyntax")?;
+        writeln!(f, "    {}", self.identifier_token)?;
+        write!(f, "{}", self.fields)?;
+        Ok(())
+    }
+}
@@ -2,10 +2,12 @@ use super::syntax_kind::SyntaxKind;
 
 pub(super) trait SyntaxFactsExt {
     fn binary_operator_precedence(&self) -> usize;
+    fn conditional_operator_precedence(&self) -> usize;
     fn unary_operator_precedence(&self) -> usize;
+    fn is_trivia(&self) -> bool;
 }
 
-pub(super) struct SyntaxFacts;
+pub(crate) struct SyntaxFacts;
 
 impl SyntaxFacts {
     pub(super) fn keyword_kind(text: &str) -> SyntaxKind {
@@ -19,19 +21,49 @@ impl SyntaxFacts {
             "while" => SyntaxKind::WhileKeyword,
             "for" => SyntaxKind::ForKeyword,
             "to" => SyntaxKind::ToKeyword,
+            "step" => SyntaxKind::StepKeyword,
+            "function" => SyntaxKind::FunctionKeyword,
+            "return" => SyntaxKind::ReturnKeyword,
+            "break" => SyntaxKind::BreakKeyword,
+            "continue" => SyntaxKind::ContinueKeyword,
+            "do" => SyntaxKind::DoKeyword,
+            "struct" => SyntaxKind::StructKeyword,
             _ => SyntaxKind::Identifier,
         }
     }
 
+    /// The binary operator a compound assignment or increment operator applies,
+    /// e.g. `+` for `+=` and `++`.
+    pub(crate) fn binary_operator_of_assignment_operator(kind: SyntaxKind) -> Option<SyntaxKind> {
+        match kind {
+            SyntaxKind::PlusEquals | SyntaxKind::PlusPlus => Some(SyntaxKind::Plus),
+            SyntaxKind::MinusEquals | SyntaxKind::MinusMinus => Some(SyntaxKind::Minus),
+            SyntaxKind::StarEquals => Some(SyntaxKind::Star),
+            SyntaxKind::SlashEquals => Some(SyntaxKind::Slash),
+            _ => None,
+        }
+    }
+
     pub(super) fn get_text(kind: SyntaxKind) -> Option<&'static str> {
         match kind {
             SyntaxKind::Plus => Some("+"),
             SyntaxKind::Minus => Some("-"),
             SyntaxKind::Star => Some("*"),
             SyntaxKind::Slash => Some("/"),
+            SyntaxKind::PlusPlus => Some("++"),
+            SyntaxKind::MinusMinus => Some("--"),
+            SyntaxKind::PlusEquals => Some("+="),
+            SyntaxKind::MinusEquals => Some("-="),
+            SyntaxKind::StarEquals => Some("*="),
+            SyntaxKind::SlashEquals => Some("/="),
+            SyntaxKind::Percent => Some("%"),
             SyntaxKind::Bang => Some("!"),
+            SyntaxKind::Tilde => Some("~"),
+            SyntaxKind::Hat => Some("^"),
             SyntaxKind::Equals => Some("="),
+            SyntaxKind::Ampersand => Some("&"),
             SyntaxKind::AmpersandAmpersand => Some("&&"),
+            SyntaxKind::Pipe => Some("|"),
             SyntaxKind::PipePipe => Some("||"),
             SyntaxKind::EqualsEquals => Some("=="),
             SyntaxKind::BangEquals => Some("!="),
@@ -39,10 +71,19 @@ impl SyntaxFacts {
             SyntaxKind::LessEquals => Some("<="),
             SyntaxKind::Greater => Some(">"),
             SyntaxKind::GreaterEquals => Some(">="),
+            SyntaxKind::LessLess => Some("<<"),
+            SyntaxKind::GreaterGreater => Some(">>"),
             SyntaxKind::OpenParenthesis => Some("("),
             SyntaxKind::CloseParenthesis => Some(")"),
             SyntaxKind::OpenBrace => Some("{"),
             SyntaxKind::CloseBrace => Some("}"),
+            SyntaxKind::OpenBracket => Some("["),
+            SyntaxKind::CloseBracket => Some("]"),
+            SyntaxKind::Colon => Some(":"),
+            SyntaxKind::Comma => Some(","),
+            SyntaxKind::Dot => Some("."),
+            SyntaxKind::QuestionMark => Some("?"),
+            SyntaxKind::Semicolon => Some(";"),
             SyntaxKind::FalseKeyword => Some("false"),
             SyntaxKind::TrueKeyword => Some("true"),
             SyntaxKind::LetKeyword => Some("let"),
@@ -52,6 +93,13 @@ impl SyntaxFacts {
             SyntaxKind::WhileKeyword => Some("while"),
             SyntaxKind::ForKeyword => Some("for"),
             SyntaxKind::ToKeyword => Some("to"),
+            SyntaxKind::StepKeyword => Some("step"),
+            SyntaxKind::FunctionKeyword => Some("function"),
+            SyntaxKind::ReturnKeyword => Some("return"),
+            SyntaxKind::BreakKeyword => Some("break"),
+            SyntaxKind::ContinueKeyword => Some("continue"),
+            SyntaxKind::DoKeyword => Some("do"),
+            SyntaxKind::StructKeyword => Some("struct"),
             _ => None,
         }
     }
@@ -60,26 +108,44 @@ impl SyntaxFacts {
 impl SyntaxFactsExt for SyntaxKind {
     fn binary_operator_precedence(&self) -> usize {
         match self {
-            SyntaxKind::Star | SyntaxKind::Slash => 5,
-            SyntaxKind::Plus | SyntaxKind::Minus => 4,
-            SyntaxKind::EqualsEquals
-            | SyntaxKind::BangEquals
-            | SyntaxKind::Less
+            SyntaxKind::Star | SyntaxKind::Slash | SyntaxKind::Percent => 11,
+            SyntaxKind::Plus | SyntaxKind::Minus => 10,
+            SyntaxKind::LessLess | SyntaxKind::GreaterGreater => 9,
+            SyntaxKind::Less
             | SyntaxKind::LessEquals
             | SyntaxKind::Greater
-            | SyntaxKind::GreaterEquals => 3,
+            | SyntaxKind::GreaterEquals => 8,
+            SyntaxKind::EqualsEquals | SyntaxKind::BangEquals => 7,
+            SyntaxKind::Ampersand => 6,
+            SyntaxKind::Hat => 5,
+            SyntaxKind::Pipe => 4,
+            SyntaxKind::AmpersandAmpersand => 3,
             SyntaxKind::PipePipe => 2,
-            SyntaxKind::AmpersandAmpersand => 1,
+            _ => 0,
+        }
+    }
+
+    /// Binds more loosely than every binary operator.
+    fn conditional_operator_precedence(&self) -> usize {
+        match self {
+            SyntaxKind::QuestionMark => 1,
             _ => 0,
         }
     }
 
     fn unary_operator_precedence(&self) -> usize {
         match self {
-            SyntaxKind::Plus | SyntaxKind::Minus | SyntaxKind::Bang => 6,
+            SyntaxKind::Plus | SyntaxKind::Minus | SyntaxKind::Bang | SyntaxKind::Tilde => 12,
             _ => 0,
         }
     }
+
+    fn is_trivia(&self) -> bool {
+        matches!(
+            self,
+            SyntaxKind::Whitespace | SyntaxKind::SingleLineComment | SyntaxKind::MultiLineComment
+        )
+    }
 }
 
 #[cfg(test)]
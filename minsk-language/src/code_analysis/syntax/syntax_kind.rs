@@ -7,15 +7,30 @@ pub(crate) enum SyntaxKind {
     BadToken,
 
     Whitespace,
+    SingleLineComment,
+    MultiLineComment,
     Number,
+    String,
+    Char,
     Identifier,
 
     Plus,
     Minus,
     Star,
     Slash,
+    PlusPlus,
+    MinusMinus,
+    PlusEquals,
+    MinusEquals,
+    StarEquals,
+    SlashEquals,
+    Percent,
     Bang,
+    Tilde,
+    Hat,
+    Ampersand,
     AmpersandAmpersand,
+    Pipe,
     PipePipe,
     Equals,
     EqualsEquals,
@@ -24,11 +39,20 @@ pub(crate) enum SyntaxKind {
     LessEquals,
     Greater,
     GreaterEquals,
+    LessLess,
+    GreaterGreater,
 
     OpenParenthesis,
     CloseParenthesis,
     OpenBrace,
     CloseBrace,
+    OpenBracket,
+    CloseBracket,
+    Colon,
+    Comma,
+    Dot,
+    QuestionMark,
+    Semicolon,
 
     // Keywowrds
     FalseKeyword,
@@ -40,6 +64,13 @@ pub(crate) enum SyntaxKind {
     WhileKeyword,
     ForKeyword,
     ToKeyword,
+    StepKeyword,
+    FunctionKeyword,
+    ReturnKeyword,
+    BreakKeyword,
+    ContinueKeyword,
+    DoKeyword,
+    StructKeyword,
 }
 
 impl Display for SyntaxKind {
@@ -10,7 +10,7 @@ use super::{
 #[derive(Debug, Clone, PartialEq)]
 pub enum SyntaxNode {
     CompilationUnit(CompilationUnit),
-    Statement(StatementSyntax),
+    Statement(Box<StatementSyntax>),
     ElseClause(ElseClauseSyntax),
 }
 
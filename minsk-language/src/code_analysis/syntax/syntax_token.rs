@@ -4,7 +4,7 @@ use crate::code_analysis::text::text_span::TextSpan;
 
 use super::super::minsk_value::MinskValue;
 
-use super::syntax_kind::SyntaxKind;
+use super::{syntax_kind::SyntaxKind, syntax_trivia::SyntaxTrivia};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct SyntaxToken {
@@ -13,6 +13,8 @@ pub struct SyntaxToken {
     pub(crate) text: String,
     pub(crate) value: Option<MinskValue>,
     pub(crate) span: TextSpan,
+    pub(crate) leading_trivia: Vec<SyntaxTrivia>,
+    pub(crate) trailing_trivia: Vec<SyntaxTrivia>,
 }
 
 impl SyntaxToken {
@@ -31,6 +33,8 @@ impl SyntaxToken {
             },
             text,
             value,
+            leading_trivia: vec![],
+            trailing_trivia: vec![],
         }
     }
 }
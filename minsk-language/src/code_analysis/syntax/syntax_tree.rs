@@ -11,6 +11,7 @@ use super::{
 pub struct SyntaxTree {
     text: SourceText,
     root: CompilationUnit,
+    is_complete: bool,
     diagnostics: DiagnosticBag,
 }
 
@@ -18,10 +19,12 @@ impl SyntaxTree {
     fn new(text: SourceText) -> Self {
         let mut parser = Parser::new(text.clone());
         let root = parser.parse_compilation_unit();
+        let is_complete = parser.is_complete();
         let diagnostics = parser.diagnostics();
         Self {
             text,
             root,
+            is_complete,
             diagnostics,
         }
     }
@@ -47,6 +50,10 @@ impl SyntaxTree {
         self.diagnostics.iter()
     }
 
+    pub fn is_complete(&self) -> bool {
+        self.is_complete
+    }
+
     pub fn root(&self) -> &CompilationUnit {
         &self.root
     }
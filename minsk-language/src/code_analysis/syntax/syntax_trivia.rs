@@ -0,0 +1,35 @@
+use std::fmt::Display;
+
+use crate::code_analysis::text::text_span::TextSpan;
+
+use super::syntax_kind::SyntaxKind;
+
+/// Source text that carries no meaning for the parser (whitespace and comments),
+/// kept on the neighbouring token so the original text can be recovered.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyntaxTrivia {
+    pub(crate) kind: SyntaxKind,
+    pub(crate) position: usize,
+    pub(crate) text: String,
+    pub(crate) span: TextSpan,
+}
+
+impl SyntaxTrivia {
+    pub(crate) fn new(kind: SyntaxKind, position: usize, text: String) -> Self {
+        Self {
+            kind,
+            position,
+            span: TextSpan {
+                start: position,
+                end: position + text.chars().count(),
+            },
+            text,
+        }
+    }
+}
+
+impl Display for SyntaxTrivia {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: '{}'", self.kind, self.text)
+    }
+}
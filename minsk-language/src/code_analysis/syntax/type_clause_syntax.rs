@@ -0,0 +1,34 @@
+use std::fmt::Display;
+
+use crate::code_analysis::text::text_span::TextSpan;
+
+use super::{syntax_token::SyntaxToken, type_syntax::TypeSyntax};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeClauseSyntax {
+    colon_token: SyntaxToken,
+    ty: TypeSyntax,
+}
+
+impl TypeClauseSyntax {
+    pub(crate) fn new(colon_token: SyntaxToken, ty: TypeSyntax) -> Self {
+        Self { colon_token, ty }
+    }
+
+    pub(crate) fn span(&self) -> TextSpan {
+        TextSpan {
+            start: self.colon_token.span.start,
+            end: self.ty.span().end,
+        }
+    }
+
+    pub(crate) fn ty(&self) -> &TypeSyntax {
+        &self.ty
+    }
+}
+
+impl Display for TypeClauseSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TypeClauseSyntax({})", self.ty)
+    }
+}
@@ -0,0 +1,29 @@
+use std::fmt::Display;
+
+use crate::code_analysis::text::text_span::TextSpan;
+
+use super::{array_type_syntax::ArrayTypeSyntax, syntax_token::SyntaxToken};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeSyntax {
+    Name(SyntaxToken),
+    Array(ArrayTypeSyntax),
+}
+
+impl TypeSyntax {
+    pub(crate) fn span(&self) -> TextSpan {
+        match self {
+            TypeSyntax::Name(identifier) => identifier.span,
+            TypeSyntax::Array(a) => a.span(),
+        }
+    }
+}
+
+impl Display for TypeSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TypeSyntax::Name(identifier) => write!(f, "{}", identifier.text),
+            TypeSyntax::Array(a) => write!(f, "{}", a),
+        }
+    }
+}
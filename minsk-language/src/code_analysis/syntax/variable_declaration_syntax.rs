@@ -2,28 +2,37 @@ use std::fmt::Display;
 
 use crate::code_analysis::text::text_span::TextSpan;
 
-use super::{expression_syntax::ExpressionSyntax, syntax_token::SyntaxToken};
+use super::{
+    expression_syntax::ExpressionSyntax, syntax_token::SyntaxToken,
+    type_clause_syntax::TypeClauseSyntax,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct VariableDeclarationSyntax {
     keyword_token: SyntaxToken,
     identifier: SyntaxToken,
+    type_clause: Option<TypeClauseSyntax>,
     equals_token: SyntaxToken,
     initializer: ExpressionSyntax,
+    semicolon_token: Option<SyntaxToken>,
 }
 
 impl VariableDeclarationSyntax {
     pub(crate) fn new(
         keyword_token: SyntaxToken,
         identifier: SyntaxToken,
+        type_clause: Option<TypeClauseSyntax>,
         equals_token: SyntaxToken,
         initializer: ExpressionSyntax,
+        semicolon_token: Option<SyntaxToken>,
     ) -> Self {
         Self {
             keyword_token,
             identifier,
+            type_clause,
             equals_token,
             initializer,
+            semicolon_token,
         }
     }
 
@@ -42,6 +51,10 @@ impl VariableDeclarationSyntax {
         &self.identifier
     }
 
+    pub(crate) fn type_clause(&self) -> Option<&TypeClauseSyntax> {
+        self.type_clause.as_ref()
+    }
+
     pub(crate) fn initializer(&self) -> &ExpressionSyntax {
         &self.initializer
     }
@@ -1,7 +1,7 @@
 use crate::smart_string::SmartString;
 
 use super::{text_line::TextLine, text_span::TextSpan};
-use std::ops::Index;
+use std::{fmt::Display, ops::Index};
 
 #[derive(Debug, Clone)]
 pub struct SourceText {
@@ -46,10 +46,7 @@ impl SourceText {
 
     fn get_line_break_width(text: &SmartString, i: usize) -> usize {
         let c = text[i];
-        let l = match text.get(i + 1) {
-            None => '\0',
-            Some(c) => c,
-        };
+        let l = text.get(i + 1).unwrap_or('\0');
 
         if c == '\r' && l == '\n' {
             2
@@ -111,9 +108,9 @@ impl Index<usize> for SourceText {
     }
 }
 
-impl ToString for SourceText {
-    fn to_string(&self) -> String {
-        self.text.to_string()
+impl Display for SourceText {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text)
     }
 }
 
@@ -1,18 +1,36 @@
+use std::{
+    hash::{Hash, Hasher},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
 use super::minsk_type::MinskType;
 
-#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub(crate) enum VariableKind {
+    Global,
+    Local,
+}
+
+/// Every declaration gets its own symbol, so symbols compare by id rather than by name: a
+/// variable shadowing another with the same name and type is still a different variable.
+#[derive(Debug, Clone)]
 pub struct VariableSymbol {
+    id: usize,
     name: String,
     read_only: bool,
     ty: MinskType,
+    kind: VariableKind,
 }
 
 impl VariableSymbol {
-    pub(crate) fn new(name: String, read_only: bool, ty: MinskType) -> Self {
+    pub(crate) fn new(name: String, read_only: bool, ty: MinskType, kind: VariableKind) -> Self {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
         Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
             name,
             read_only,
             ty,
+            kind,
         }
     }
 
@@ -27,4 +45,22 @@ impl VariableSymbol {
     pub(crate) fn name(&self) -> &str {
         &self.name
     }
+
+    pub(crate) fn is_local(&self) -> bool {
+        self.kind == VariableKind::Local
+    }
+}
+
+impl PartialEq for VariableSymbol {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for VariableSymbol {}
+
+impl Hash for VariableSymbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state)
+    }
 }
@@ -1,4 +1,7 @@
-use std::ops::{Index, Range, RangeTo};
+use std::{
+    fmt::Display,
+    ops::{Index, Range, RangeTo},
+};
 
 #[derive(Debug, Clone)]
 pub struct SmartString {
@@ -45,8 +48,8 @@ impl Index<Range<usize>> for SmartString {
     }
 }
 
-impl ToString for SmartString {
-    fn to_string(&self) -> String {
-        self.text.iter().collect()
+impl Display for SmartString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text.iter().collect::<String>())
     }
 }